@@ -0,0 +1,58 @@
+//! Compares canonical ISO input, which takes the allocation-free fast path,
+//! with equivalent fuzzy input that needs normalising first, for parsing
+//! and for normalised strings borrowed from clean input.
+//! Run with `cargo bench --bench iso_fast_path`
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use fuzzy_datetime::{fuzzy_to_datetime, normalize_datetime_string};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 100_000;
+
+fn run<T>(label: &str, sample: &str, parse: fn(&str) -> T) -> usize {
+  let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+  let start = Instant::now();
+  for _ in 0..ITERATIONS {
+    black_box(parse(black_box(sample)));
+  }
+  let elapsed = start.elapsed();
+  let allocations = ALLOCATIONS.load(Ordering::Relaxed) - start_allocations;
+  println!(
+    "{:<10} {:<28} {:>8.1} ns/iter {:>6.2} allocations/iter",
+    label,
+    sample,
+    elapsed.as_nanos() as f64 / ITERATIONS as f64,
+    allocations as f64 / ITERATIONS as f64
+  );
+  allocations
+}
+
+fn main() {
+  let parse = |dt: &str| fuzzy_to_datetime(dt, None, None).ok();
+  let fast_allocations = run("canonical", "2023-08-29T19:34:39.678Z", parse);
+  run("fuzzy", "29/08/2023 19:34:39.678", parse);
+  assert_eq!(fast_allocations, 0, "canonical ISO input should not allocate");
+  let normalize = |dt: &str| normalize_datetime_string(dt, None).is_some();
+  let borrowed_allocations = run("borrowed", "2023-08-29T19:34:39.678Z", normalize);
+  run("owned", "29/08/2023 19:34:39.678", normalize);
+  assert_eq!(borrowed_allocations, 0, "normalised input should be borrowed without allocating");
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDate;
+use simple_string_patterns::CharGroupMatch;
+use crate::{
+  converters::{days_in_month, expand_two_digit_year_by},
+  fuzzy_to_date, repair_datetime, split_datetime_segments, DateOptions,
+};
+
+/// Always return a date, approximating if need be, e.g. for dashboards that must show something.
+/// This is deliberately lossy: after the normal parser and the typo repairs of repair_datetime,
+/// an out-of-range month or day is clamped, e.g. "2023-13-32" => 2023-12-31, and failing that
+/// the first plausible 4-digit year anywhere in the text gives 1 January of that year.
+/// If no year can be found at all, 1970-01-01 is returned. Use fuzzy_to_date to detect invalid input
+pub fn parse_best_effort(s: &str, date_opts: Option<DateOptions>) -> NaiveDate {
+  fuzzy_to_date(s, date_opts.clone()).ok()
+    .or_else(|| repair_datetime(s).and_then(|repaired| fuzzy_to_date(&repaired, None).ok()))
+    .or_else(|| clamp_date_components(s, date_opts))
+    .or_else(|| first_plausible_year(s).and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1)))
+    .unwrap_or_default()
+}
+
+/// Clamp the month to 1-12 and the day to the length of the month in a date with 3 numeric components
+fn clamp_date_components(s: &str, date_opts: Option<DateOptions>) -> Option<NaiveDate> {
+  let date = split_datetime_segments(s).date;
+  let opts = date_opts.unwrap_or_else(DateOptions::auto).resolve(date);
+  let parts: Vec<&str> = date.split(opts.splitter()?).collect();
+  if parts.len() != 3 || parts.iter().any(|part| part.is_empty() || !part.is_digits_only() || part.len() > 4) {
+    return None;
+  }
+  let (yr_idx, month_idx, day_idx) = opts.order().to_ymd_indices();
+  let number = |idx: usize| parts[idx].parse::<u16>().ok();
+  let year = match parts[yr_idx].len() {
+    1 | 2 => expand_two_digit_year_by(number(yr_idx)?, opts.two_digit_years()),
+    4 => number(yr_idx)?,
+    _ => return None,
+  };
+  let month = number(month_idx)?.clamp(1, 12) as u8;
+  let day = number(day_idx)?.clamp(1, days_in_month(year, month) as u16);
+  NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+}
+
+/// The first run of exactly 4 digits from 1000 to 9999, e.g. 2023 in "FY2023 report"
+fn first_plausible_year(s: &str) -> Option<i32> {
+  s.split(|c: char| !c.is_ascii_digit())
+    .find(|digits| digits.len() == 4 && !digits.starts_with('0'))
+    .and_then(|digits| digits.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_best_effort() {
+    assert_eq!(parse_best_effort("2023-08-29", None), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap());
+    assert_eq!(parse_best_effort("20023-08-29", None), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap());
+    assert_eq!(parse_best_effort("2023-13-32", None), NaiveDate::from_ymd_opt(2023, 12, 31).unwrap());
+    assert_eq!(parse_best_effort("31/02/2023", None), NaiveDate::from_ymd_opt(2023, 2, 28).unwrap());
+    assert_eq!(parse_best_effort("29/02/24", Some(DateOptions::dmy('/'))), NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    assert_eq!(parse_best_effort("FY2023 report", None), NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+    assert_eq!(parse_best_effort("sometime in 1998 or so", None), NaiveDate::from_ymd_opt(1998, 1, 1).unwrap());
+    assert_eq!(parse_best_effort("no date here", None), NaiveDate::from_ymd_opt(1970, 1, 1).unwrap());
+  }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use chrono::{Datelike, NaiveDate};
+use crate::{fuzzy_to_date, DateOptions, Period};
+
+/// Parse a list of date strings and group the dates by period, keyed as "2023" for a year, "2023-08" for a month,
+/// "2023-Q3" for a quarter or "2023-W35" for an ISO week, e.g. to aggregate a messy date column as a time series.
+/// Dates keep their order within each group and strings that are not dates are skipped
+pub fn bucket_by(list: &[&str], period: Period, date_opts: Option<DateOptions>) -> HashMap<String, Vec<NaiveDate>> {
+  let mut buckets: HashMap<String, Vec<NaiveDate>> = HashMap::new();
+  for &dt in list {
+    if let Ok(date) = fuzzy_to_date(dt, date_opts.clone()) {
+      buckets.entry(period_key(date, period)).or_default().push(date);
+    }
+  }
+  buckets
+}
+
+fn period_key(date: NaiveDate, period: Period) -> String {
+  match period {
+    Period::Year => format!("{:04}", date.year()),
+    Period::Month => format!("{:04}-{:02}", date.year(), date.month()),
+    Period::Quarter => format!("{:04}-Q{}", date.year(), date.month0() / 3 + 1),
+    Period::Week => {
+      let week = date.iso_week();
+      format!("{:04}-W{:02}", week.year(), week.week())
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_bucket_by() {
+    let list = ["29/08/2023", "2023-08-01", "n/a", "5 September 2023", "31.08.2023 19:34"];
+    let by_month = bucket_by(&list, Period::Month, None);
+    assert_eq!(by_month.len(), 2);
+    assert_eq!(by_month["2023-08"], vec![NaiveDate::from_ymd_opt(2023, 8, 29).unwrap(), NaiveDate::from_ymd_opt(2023, 8, 1).unwrap(), NaiveDate::from_ymd_opt(2023, 8, 31).unwrap()]);
+    assert_eq!(by_month["2023-09"], vec![NaiveDate::from_ymd_opt(2023, 9, 5).unwrap()]);
+    assert_eq!(bucket_by(&list, Period::Quarter, None)["2023-Q3"].len(), 4);
+    assert_eq!(bucket_by(&list, Period::Year, None)["2023"].len(), 4);
+    // 1 January 2023 falls in the last ISO week of 2022
+    let by_week = bucket_by(&["2023-01-01", "2023-01-02"], Period::Week, None);
+    assert_eq!(by_week["2022-W52"], vec![NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()]);
+    assert_eq!(by_week["2023-W01"], vec![NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()]);
+  }
+}
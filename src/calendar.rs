@@ -0,0 +1,61 @@
+use chrono::NaiveDate;
+use simple_string_patterns::CharGroupMatch;
+use to_segments::ToSegments;
+use crate::{converters::digits_to_date_parts, segments::split_datetime_segments, DateOptions, DateOrder};
+
+/// Extension point for dates in non-Gregorian calendars, e.g. Hebrew or Islamic dates
+/// The crate only parses the fuzzy numeric components. Implementors supply the conversion tables
+pub trait CalendarConverter {
+  /// convert a year, month and day in the source calendar to a Gregorian date
+  fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate>;
+}
+
+/// Parse the year, month and day of a date in another calendar with the same date order and
+/// splitter options as Gregorian dates, then convert it via the supplied calendar converter
+/// Months up to 13 are accepted to cater for leap months, while all three components are required
+pub fn fuzzy_calendar_to_date<C: CalendarConverter>(dt: &str, date_opts: Option<DateOptions>, converter: &C) -> Option<NaiveDate> {
+  let date_str = split_datetime_segments(dt).date;
+  let date_options = date_opts.unwrap_or_else(DateOptions::auto).resolve(date_str);
+  let parts: Vec<String> = if let Some(split_char) = date_options.splitter() {
+    date_str.to_parts(&split_char.to_string())
+  } else {
+    digits_to_date_parts(date_str, date_options.order())
+  };
+  if parts.len() != 3 || parts.iter().any(|part| part.is_empty() || !part.is_digits_only()) {
+    return None;
+  }
+  // compact dates are sliced into year, month and day by digits_to_date_parts, whatever the order
+  let (yr_idx, month_idx, day_idx) = if date_options.splitter().is_some() { date_options.order().to_ymd_indices() } else { DateOrder::YMD.to_ymd_indices() };
+  let year = parts[yr_idx].parse::<i32>().ok()?;
+  let month = parts[month_idx].parse::<u32>().ok()?;
+  let day = parts[day_idx].parse::<u32>().ok()?;
+  if !(1..=13).contains(&month) || !(1..=31).contains(&day) {
+    return None;
+  }
+  converter.to_gregorian(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A stand-in calendar whose years run 100 years behind the Gregorian calendar
+  struct StubCalendar;
+
+  impl CalendarConverter for StubCalendar {
+    fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+      NaiveDate::from_ymd_opt(year + 100, month.min(12), day)
+    }
+  }
+
+  #[test]
+  fn test_fuzzy_calendar_to_date() {
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29);
+    assert_eq!(fuzzy_calendar_to_date("1923-08-29", None, &StubCalendar), expected);
+    assert_eq!(fuzzy_calendar_to_date("29/8/1923", Some(DateOptions::dmy('/')), &StubCalendar), expected);
+    // leap months are passed through to the converter
+    assert_eq!(fuzzy_calendar_to_date("1923-13-01", None, &StubCalendar), NaiveDate::from_ymd_opt(2023, 12, 1));
+    assert_eq!(fuzzy_calendar_to_date("1923-08", None, &StubCalendar), None);
+    assert_eq!(fuzzy_calendar_to_date("1923-14-01", None, &StubCalendar), None);
+  }
+}
@@ -0,0 +1,61 @@
+use chrono::NaiveDateTime;
+use crate::{fuzzy_to_datetime, segments::split_datetime_segments, surmise_date_order_and_splitter, DateOptions, DateOrder};
+
+/// Return every valid interpretation of an ambiguous date-time string with a heuristic confidence,
+/// sorted best-first, e.g. "01/02/03" may be 1 February 2003, 2 January 2003 or 3 February 2001.
+///
+/// Each date order that yields a valid date-time is weighted as follows:
+/// - 2 if it matches the order guessed from the string itself, otherwise 1
+/// - halved for a year-first reading without a 4-digit leading year, as 2-digit years rarely come first
+///
+/// Orders that yield the same date-time share one candidate with their weights combined,
+/// and confidences are the weights divided by their total, so they add up to 1.
+/// Candidates with equal confidence are sorted chronologically
+pub fn parse_candidates(s: &str) -> Vec<(NaiveDateTime, f32)> {
+  let guessed = surmise_date_order_and_splitter(s);
+  let splitter = guessed.splitter();
+  let has_long_leading_year = match splitter {
+    Some(split_char) => split_datetime_segments(s).date.split(split_char).next().is_some_and(|first| first.trim().len() == 4),
+    None => true,
+  };
+  let mut candidates: Vec<(NaiveDateTime, f32)> = vec![];
+  for order in [DateOrder::YMD, DateOrder::DMY, DateOrder::MDY] {
+    let Ok(datetime) = fuzzy_to_datetime(s, Some(DateOptions::new(order, splitter)), None) else {
+      continue;
+    };
+    let mut weight = if order == guessed.order() { 2.0 } else { 1.0 };
+    if order == DateOrder::YMD && !has_long_leading_year {
+      weight /= 2.0;
+    }
+    match candidates.iter_mut().find(|(existing, _)| *existing == datetime) {
+      Some(candidate) => candidate.1 += weight,
+      None => candidates.push((datetime, weight)),
+    }
+  }
+  let total: f32 = candidates.iter().map(|(_, weight)| weight).sum();
+  for candidate in candidates.iter_mut() {
+    candidate.1 /= total;
+  }
+  candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+  candidates
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_parse_candidates() {
+    let candidates = parse_candidates("01/02/03");
+    let datetimes: Vec<NaiveDateTime> = candidates.iter().map(|(datetime, _)| *datetime).collect();
+    assert_eq!(datetimes, vec![NaiveDate::from_ymd_opt(2003, 2, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(), NaiveDate::from_ymd_opt(2003, 1, 2).unwrap().and_hms_opt(0, 0, 0).unwrap(), NaiveDate::from_ymd_opt(2001, 2, 3).unwrap().and_hms_opt(0, 0, 0).unwrap()]);
+    let total: f32 = candidates.iter().map(|(_, confidence)| confidence).sum();
+    assert!((total - 1.0).abs() < 1e-6);
+    assert!(candidates[0].1 > candidates[1].1 && candidates[1].1 > candidates[2].1);
+    // an unambiguous date has a single certain candidate
+    assert_eq!(parse_candidates("2023-08-29"), vec![(NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0).unwrap(), 1.0)]);
+    assert_eq!(parse_candidates("29/08/2023"), vec![(NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0).unwrap(), 1.0)]);
+    assert!(parse_candidates("not a date").is_empty());
+  }
+}
@@ -0,0 +1,94 @@
+use chrono::NaiveDateTime;
+use crate::{converters::{canonical_iso_to_datetime, named_month_date_to_iso}, date_order::TwoDigitYears, fuzzy_to_datetime, DateOptions};
+
+/// A strategy tried by a ParserChain
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseStage {
+  /// Canonical ISO 8601 date-times only, e.g. "2023-08-29T19:34:39Z", without normalisation
+  Iso,
+  /// The fuzzy parser with fixed options
+  Options(DateOptions),
+  /// The fuzzy parser guessing the date order and splitter
+  Guess,
+  /// Dates with a month name, e.g. "29 August 2023" or "Aug 29, 2023 19:34"
+  NamedMonth,
+}
+
+impl ParseStage {
+  fn parse(&self, s: &str) -> Option<NaiveDateTime> {
+    match self {
+      ParseStage::Iso => canonical_iso_to_datetime(s.trim()),
+      ParseStage::Options(date_opts) => fuzzy_to_datetime(s, Some(date_opts.clone()), None).ok(),
+      ParseStage::Guess => fuzzy_to_datetime(s, None, None).ok(),
+      ParseStage::NamedMonth => {
+        let (iso_date, rest) = named_month_date_to_iso(s.trim(), false, &[], TwoDigitYears::default())?;
+        fuzzy_to_datetime(format!("{} {}", iso_date, rest).trim(), Some(DateOptions::default()), None).ok()
+      },
+    }
+  }
+}
+
+/// An ordered sequence of parse strategies returning the first success, e.g. to try the fast strict ISO parser
+/// before falling back to known options and then guessing. ParserChain::default() tries ISO, guessing and named months
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParserChain {
+  stages: Vec<ParseStage>,
+}
+
+impl ParserChain {
+  /// An empty chain, to which stages are added in order with then()
+  pub fn new() -> Self {
+    ParserChain { stages: vec![] }
+  }
+
+  pub fn then(mut self, stage: ParseStage) -> Self {
+    self.stages.push(stage);
+    self
+  }
+
+  pub fn stages(&self) -> &[ParseStage] {
+    &self.stages
+  }
+
+  pub fn parse(&self, s: &str) -> Option<NaiveDateTime> {
+    self.parse_with_stage(s).map(|(datetime, _)| datetime)
+  }
+
+  /// As above, but also return the stage that succeeded
+  pub fn parse_with_stage(&self, s: &str) -> Option<(NaiveDateTime, &ParseStage)> {
+    self.stages.iter().find_map(|stage| stage.parse(s).map(|datetime| (datetime, stage)))
+  }
+}
+
+impl Default for ParserChain {
+  fn default() -> Self {
+    ParserChain::new()
+      .then(ParseStage::Iso)
+      .then(ParseStage::Guess)
+      .then(ParseStage::NamedMonth)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_parser_chain() {
+    let dmy = DateOptions::dmy('/');
+    let chain = ParserChain::new()
+      .then(ParseStage::Iso)
+      .then(ParseStage::Options(dmy.clone()))
+      .then(ParseStage::Guess);
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(chain.parse_with_stage("2023-08-29T00:00:00Z"), Some((expected, &ParseStage::Iso)));
+    assert_eq!(chain.parse_with_stage("29/08/2023"), Some((expected, &ParseStage::Options(dmy))));
+    assert_eq!(chain.parse_with_stage("2023.08.29"), Some((expected, &ParseStage::Guess)));
+    assert_eq!(chain.parse("apples"), None);
+    let chain = ParserChain::new().then(ParseStage::Iso).then(ParseStage::NamedMonth);
+    assert_eq!(chain.parse_with_stage("Aug 29, 2023"), Some((expected, &ParseStage::NamedMonth)));
+    assert_eq!(chain.parse("29/08/2023"), None);
+    assert_eq!(ParserChain::default().parse("29 August 2023"), Some(expected));
+  }
+}
@@ -0,0 +1,129 @@
+/// How hours are read when a time may or may not carry an AM/PM marker
+/// Hours of 0 or 13 to 23 without a marker, e.g. 13:00, are always read as 24-hour times
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Clock {
+  /// Apply AM/PM when present and read bare times as 24-hour times
+  #[default]
+  Auto,
+  /// Times are on a 12-hour clock, so hours from 1 to 12 without AM/PM are rejected as ambiguous
+  Clock12,
+  /// Times are on a 24-hour clock, so AM/PM markers are rejected
+  Clock24,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Meridiem {
+  Am,
+  Pm,
+}
+
+/// Lowercase AM/PM markers and their localized equivalents, such as the Chinese 上午 and 下午
+const MERIDIEM_MARKERS: [(&str, Meridiem); 4] = [
+  ("am", Meridiem::Am),
+  ("pm", Meridiem::Pm),
+  ("上午", Meridiem::Am),
+  ("下午", Meridiem::Pm),
+];
+
+/// Check whether a word is an AM/PM marker without dots, e.g. "PM" or "下午"
+pub(crate) fn is_meridiem_marker(word: &str) -> bool {
+  let lc = word.to_lowercase();
+  MERIDIEM_MARKERS.iter().any(|(name, _)| *name == lc)
+}
+
+/// Split a trailing AM/PM marker, with or without a leading space or dots, e.g. "7:34 PM", "7:34pm" or "7:34 p.m.",
+/// from a date-time string. The preceding text must end with a digit
+pub(crate) fn split_meridiem(text: &str) -> Option<(&str, Meridiem)> {
+  let trimmed = text.trim_end();
+  let base = trimmed.trim_end_matches(|c: char| c.is_alphabetic() || c == '.');
+  let marker: String = trimmed[base.len()..].chars().filter(|&c| c != '.').collect::<String>().to_lowercase();
+  let (_, meridiem) = MERIDIEM_MARKERS.iter().find(|(name, _)| *name == marker)?;
+  let meridiem = *meridiem;
+  let base = base.trim_end();
+  if base.ends_with(|c: char| c.is_ascii_digit()) {
+    Some((base, meridiem))
+  } else {
+    None
+  }
+}
+
+/// Move a localized marker that precedes the time, as in Chinese, after it,
+/// e.g. "2023-08-29 下午 7:15" => "2023-08-29 7:15 下午", so it can be split as a trailing marker
+pub(crate) fn move_leading_meridiem(text: &str) -> Option<String> {
+  MERIDIEM_MARKERS.iter().filter(|(name, _)| !name.is_ascii()).find_map(|(name, _)| {
+    let (before, after) = text.split_once(name)?;
+    let (before, after) = (before.trim_end(), after.trim());
+    if !after.starts_with(|c: char| c.is_ascii_digit()) {
+      return None;
+    }
+    if before.is_empty() {
+      Some(format!("{} {}", after, name))
+    } else {
+      Some(format!("{} {} {}", before, after, name))
+    }
+  })
+}
+
+/// Convert the hour of a time string such as "7:34:39" or "0734" to the 24-hour clock according to
+/// the AM/PM marker, if any, and the clock mode. Returns None if the hour is invalid for the marker or mode
+pub(crate) fn to_24_hour(time: &str, meridiem: Option<Meridiem>, clock: Clock) -> Option<String> {
+  let (hour_str, rest) = split_leading_hour(time);
+  if hour_str.is_empty() {
+    return Some(time.to_string());
+  }
+  let hour = hour_str.parse::<u8>().ok()?;
+  let hour = match (meridiem, clock) {
+    (Some(_), Clock::Clock24) => return None,
+    (Some(_), _) if !(1..=12).contains(&hour) => return None,
+    (Some(Meridiem::Am), _) => hour % 12,
+    (Some(Meridiem::Pm), _) => hour % 12 + 12,
+    (None, Clock::Clock12) if (1..=12).contains(&hour) => return None,
+    (None, _) => return Some(time.to_string()),
+  };
+  Some(format!("{:02}{}", hour, rest))
+}
+
+/// Split the hour digits from the rest of a time, e.g. "7" and ":34" in "7:34" or "07" and "34" in "0734"
+pub(crate) fn split_leading_hour(time: &str) -> (&str, &str) {
+  let num_digits = time.chars().take_while(|c| c.is_ascii_digit()).count();
+  // compact times such as 0734 or 734 have 2 or 1 hour digits
+  let hour_len = if num_digits <= 2 { num_digits } else { 2 - num_digits % 2 };
+  time.split_at(hour_len)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_meridiem() {
+    assert_eq!(split_meridiem("2023-08-29 7:34:39 PM"), Some(("2023-08-29 7:34:39", Meridiem::Pm)));
+    assert_eq!(split_meridiem("07:34am"), Some(("07:34", Meridiem::Am)));
+    assert_eq!(split_meridiem("7:34 p.m."), Some(("7:34", Meridiem::Pm)));
+    assert_eq!(split_meridiem("19:34:39Z"), None);
+    assert_eq!(split_meridiem("29 Aug pm"), None);
+    assert_eq!(split_meridiem("7:15 下午"), Some(("7:15", Meridiem::Pm)));
+  }
+
+  #[test]
+  fn test_move_leading_meridiem() {
+    assert_eq!(move_leading_meridiem("下午 7:15"), Some("7:15 下午".to_string()));
+    assert_eq!(move_leading_meridiem("2023-08-29上午7:15"), Some("2023-08-29 7:15 上午".to_string()));
+    assert_eq!(move_leading_meridiem("7:15 下午"), None);
+    assert_eq!(move_leading_meridiem("2023-08-29 7:15 PM"), None);
+  }
+
+  #[test]
+  fn test_to_24_hour() {
+    assert_eq!(to_24_hour("7:34:39", Some(Meridiem::Pm), Clock::Auto), Some("19:34:39".to_string()));
+    assert_eq!(to_24_hour("12:00", Some(Meridiem::Am), Clock::Auto), Some("00:00".to_string()));
+    assert_eq!(to_24_hour("12:00", Some(Meridiem::Pm), Clock::Clock12), Some("12:00".to_string()));
+    assert_eq!(to_24_hour("734", Some(Meridiem::Pm), Clock::Auto), Some("1934".to_string()));
+    assert_eq!(to_24_hour("13:00", Some(Meridiem::Pm), Clock::Auto), None);
+    assert_eq!(to_24_hour("7:34", Some(Meridiem::Pm), Clock::Clock24), None);
+    assert_eq!(to_24_hour("7:15", None, Clock::Clock12), None);
+    for clock in [Clock::Auto, Clock::Clock12, Clock::Clock24] {
+      assert_eq!(to_24_hour("13:00", None, clock), Some("13:00".to_string()));
+    }
+  }
+}
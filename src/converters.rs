@@ -1,8 +1,8 @@
 use std::vec;
-use chrono::{Datelike, Utc};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeDelta, Utc, Weekday};
 use simple_string_patterns::{CharGroupMatch, StripCharacters};
 use to_segments::ToSegments;
-use crate::{guess::guess_time_splitter, DateOrder};
+use crate::{date_order::{TwoDigitYears, TwoPartDates}, guess::guess_time_splitter, offset::{format_utc_offset, utc_offset_minutes}, DateOrder, FuzzyDateError, PeriodAnchor};
 
 /// How many years into the future a 2-digit year is still expanded to the current
 /// century before rolling back to the previous one -- e.g. with today in 2026, "46"
@@ -15,7 +15,7 @@ const PIVOT_YEARS_AHEAD: u16 = 20;
 /// a historical/astronomical date) are returned unchanged -- this only ever applies to
 /// genuinely ambiguous 2-digit shorthand, common in spreadsheet/CSV date cells (e.g.
 /// "21-06-23"), not to short-but-real historical years.
-fn expand_two_digit_year(yr: u16) -> u16 {
+pub(crate) fn expand_two_digit_year(yr: u16) -> u16 {
   if yr >= 100 {
     return yr;
   }
@@ -30,14 +30,53 @@ fn expand_two_digit_year(yr: u16) -> u16 {
   }
 }
 
-/// convert the state component of a date-time string to a valid ISO-compatible string
-pub(crate) fn to_formatted_date_string(date_srr: &str,date_order: DateOrder, splitter: Option<char>) -> Option<String> {
+/// number of days in a given month, accounting for leap years
+pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+  match month {
+    4 | 6 | 9 | 11 => 30,
+    2 => if NaiveDate::from_ymd_opt(year as i32, 2, 29).is_some() { 29 } else { 28 },
+    _ => 31,
+  }
+}
+
+/// Expand a 2-digit year with a fixed pivot, so with 69, "68" => 2068 and "69" => 1969,
+/// within a window ending a number of years after a reference year, or with the sliding window above
+pub(crate) fn expand_two_digit_year_by(yr: u16, rule: TwoDigitYears) -> u16 {
+  if yr >= 100 {
+    return yr;
+  }
+  match rule {
+    TwoDigitYears::Sliding => expand_two_digit_year(yr),
+    TwoDigitYears::Pivot(pivot) => if yr < pivot as u16 { 2000 + yr } else { 1900 + yr },
+    TwoDigitYears::Window { reference_year, years_ahead } => {
+      let latest_year = reference_year + years_ahead as u16;
+      let candidate = (latest_year / 100) * 100 + yr;
+      if candidate <= latest_year { candidate } else { candidate - 100 }
+    },
+  }
+}
+
+/// convert the date component of a date-time string to a valid ISO-compatible string
+/// Missing months and days resolve to the end of the year or month with PeriodAnchor::End,
+/// and 2-digit years are expanded by the given rule
+pub(crate) fn to_formatted_date_string_anchored(date_srr: &str,date_order: DateOrder, splitter: Option<char>, anchor: PeriodAnchor, two_digit_years: TwoDigitYears) -> Option<String> {
+  try_to_formatted_date_string_anchored(date_srr, date_order, splitter, anchor, two_digit_years).ok()
+}
+
+/// As above, but with the reason the date components are invalid
+pub(crate) fn try_to_formatted_date_string_anchored(date_srr: &str,date_order: DateOrder, splitter: Option<char>, anchor: PeriodAnchor, two_digit_years: TwoDigitYears) -> Result<String, FuzzyDateError> {
     let parts: Vec<String> = if let Some(split_char) = splitter {
       date_srr.to_parts(&split_char.to_string())
     } else {
-      digits_to_date_parts(date_srr, date_order)
+      // a compact date must be all digits, rather than silently concatenating digits
+      // either side of other characters, e.g. "2023ab0829"
+      if !date_srr.trim().is_digits_only() {
+        return Err(FuzzyDateError::NonDateText(date_srr.trim().to_string()));
+      }
+      digits_to_date_parts(date_srr.trim(), date_order)
     };
-    let (yr_idx, month_idx, day_idx) = date_order.to_ymd_indices();
+    // compact dates are sliced into year, month and day by digits_to_date_parts, whatever the order
+    let (yr_idx, month_idx, day_idx) = if splitter.is_some() { date_order.to_ymd_indices() } else { DateOrder::YMD.to_ymd_indices() };
     let mut date_parts: Vec<u16> = parts.into_iter()
       .filter(|n| n.is_digits_only())
       .map(|dp| dp.parse::<u16>().unwrap_or(0))
@@ -60,46 +99,81 @@ pub(crate) fn to_formatted_date_string(date_srr: &str,date_order: DateOrder, spl
     // use case, e.g. "1678-6" for June 1678) is unaffected either way, since it already
     // carries a real 4-digit year needing no expansion at all.
     let yr_raw = date_parts[yr_idx];
-    let yr = if splitter == Some(':') || num_parts < 3 { yr_raw } else { expand_two_digit_year(yr_raw) };
+    let yr = if splitter == Some(':') || num_parts < 3 { yr_raw } else { expand_two_digit_year_by(yr_raw, two_digit_years) };
+    if yr_raw == 0 || (yr < 1000 && num_parts < 3) {
+      return Err(FuzzyDateError::NoYear);
+    }
     if yr < 1000 {
-      return None;
+      return Err(FuzzyDateError::YearTooSmall(yr));
     }
+    let at_end = anchor == PeriodAnchor::End;
     let mut month = date_parts[month_idx];
-    // default 0 for a missing month will be set to 1
+    // default 0 for a missing month will be set to 1 or 12 at the end of the year
     if month < 1 {
-      month = 1
+      month = if at_end { 12 } else { 1 };
     }
     if month > 12 {
-      return None;
+      return Err(FuzzyDateError::MonthOutOfRange(month));
     }
-    // default 0 for a missing day will be set to 1
+    // default 0 for a missing day will be set to 1 or the last day of the month
     let mut day = date_parts[day_idx];
     if day < 1 {
-      day = 1
+      day = if at_end { days_in_month(yr, month as u8) as u16 } else { 1 };
     }
     if day > 31 {
-      return None;
+      return Err(FuzzyDateError::DayOutOfRange(day));
     }
-    Some(format!("{:04}-{:02}-{:02}", yr, month, day))
+    Ok(format!("{:04}-{:02}-{:02}", yr, month, day))
   }
 
 /// extract the time and millseconds components of a date-time string
-pub(crate) fn fuzzy_to_formatted_time_parts(time_part: &str, ms_tz: &str, time_separator: Option<char>, add_z: bool) -> Option<(String, String)> {
+pub(crate) fn fuzzy_to_formatted_time_parts(time_part: &str, ms_tz: &str, time_separator: Option<char>, add_z: bool, subsecond_separator: char, subsecond_digits: u8) -> Option<(String, String)> {
+  try_fuzzy_to_formatted_time_parts(time_part, ms_tz, time_separator, add_z, subsecond_separator, subsecond_digits).ok()
+}
+
+/// As above, but with the reason the time components are invalid
+pub(crate) fn try_fuzzy_to_formatted_time_parts(time_part: &str, ms_tz: &str, time_separator: Option<char>, add_z: bool, subsecond_separator: char, subsecond_digits: u8) -> Result<(String, String), FuzzyDateError> {
+  // times with unit suffixes such as 19h34m39s are read as colon-separated, whatever the time separator
+  let unit_time = unit_suffixed_time_to_time(time_part);
+  let (time_part, time_separator) = match &unit_time {
+    Some(hms) => (hms.as_str(), Some(':')),
+    None => (time_part, time_separator),
+  };
+  let fractional_minutes = if time_separator.is_none_or(|sep| sep == ':') {
+    fractional_minutes_to_time(time_part)
+  } else {
+    None
+  };
+  let (time_part, ms_tz) = match &fractional_minutes {
+    Some((hms, ms)) => (hms.as_str(), ms.as_str()),
+    None => (time_part, ms_tz),
+  };
   let t_split_opt = if let Some(t_splitter) = time_separator {
     Some(t_splitter)
   } else {
     guess_time_splitter(time_part)
   };
-  let t_parts: Vec<&str> = if let Some(t_split) = t_split_opt {
+  let t_parts: Vec<&str> = if time_separator.is_none() && t_split_opt.is_some() {
+    // guessed separators may be mixed in sloppy data, e.g. 19:34.39 or 19.34:39
+    time_part.split([':', '.']).collect()
+  } else if let Some(t_split) = t_split_opt {
     time_part.split(t_split).collect()
   } else {
-    vec![time_part[0..2].as_ref(), time_part[2..4].as_ref(), time_part[4..6].as_ref()]
+    // compact times have 2-digit hours, minutes and optional seconds, e.g. 1934 or 193439
+    if !matches!(time_part.len(), 2 | 4 | 6) || !time_part.is_digits_only() {
+      return Err(FuzzyDateError::InvalidTime(time_part.to_string()));
+    }
+    (0..time_part.len()).step_by(2).map(|idx| &time_part[idx..idx + 2]).collect()
   };
   if let Some(&first) = t_parts.first() {
     if !first.is_digits_only() {
-      return None;
+      return Err(FuzzyDateError::InvalidTime(time_part.to_string()));
     }
   }
+  // components have at most 2 digits, e.g. not the 345 in 1.2.345
+  if t_parts.iter().any(|part| part.len() > 2 && part.is_digits_only()) {
+    return Err(FuzzyDateError::InvalidTime(time_part.to_string()));
+  }
   let mut time_parts: Vec<u8> = t_parts.into_iter()
   .filter(|&n| n.is_digits_only())
   .map(|tp| tp.parse::<u8>().unwrap_or(0))
@@ -110,32 +184,489 @@ pub(crate) fn fuzzy_to_formatted_time_parts(time_part: &str, ms_tz: &str, time_s
   }
   let hrs = time_parts[0];
   if hrs > 23 {
-      return None;
+      return Err(FuzzyDateError::HourOutOfRange(hrs));
   }
   let mins = time_parts[1];
   if mins > 59 {
-      return None;
+      return Err(FuzzyDateError::MinuteOutOfRange(mins));
   }
   let secs = time_parts[2];
   if secs > 59 {
-      return None;
+      return Err(FuzzyDateError::SecondOutOfRange(secs));
   }
   let formatted_time = format!("{:02}:{:02}:{:02}", hrs, mins, secs);
   let tz_suffix = if add_z {
-      let max_len = if ms_tz.len() > 3 {
-      3
-      } else {
-      ms_tz.len()
-      };
-      let ms = ms_tz[0..max_len].parse::<u16>().unwrap_or(0);
-      format!(".{:03}Z", ms)
+      let subseconds = format_subseconds(subsecond_digits_to_nanos(ms_tz), subsecond_digits);
+      // preserve a numeric UTC offset after the subseconds, otherwise assume UTC
+      let zone = ms_tz.trim_start_matches(|c: char| c.is_ascii_digit());
+      let offset = if zone.starts_with(['+', '-']) { utc_offset_minutes(zone).map(format_utc_offset) } else { None };
+      format!("{}{}{}", subsecond_separator, subseconds, offset.as_deref().unwrap_or("Z"))
   } else {
       "".to_string()
   };
-  Some((formatted_time, tz_suffix))
+  Ok((formatted_time, tz_suffix))
+}
+
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+  "january", "february", "march", "april", "may", "june",
+  "july", "august", "september", "october", "november", "december",
+];
+
+/// Full German and French month names that are not English names or abbreviations, with and without accents
+const FOREIGN_MONTH_NAMES: [(&str, u8); 21] = [
+  ("märz", 3), ("maerz", 3), ("mai", 5), ("juni", 6), ("juli", 7), ("oktober", 10), ("dezember", 12),
+  ("janvier", 1), ("février", 2), ("fevrier", 2), ("mars", 3), ("avril", 4), ("juin", 6), ("juillet", 7),
+  ("août", 8), ("aout", 8), ("septembre", 9), ("octobre", 10), ("novembre", 11), ("décembre", 12), ("decembre", 12),
+];
+
+/// German and French articles that may precede a day, e.g. "den" in "den 29. August 2023" or "le" in "le 29 août 2023"
+const DAY_ARTICLES: [&str; 3] = ["den", "der", "le"];
+
+/// Parse canonical ISO 8601 date-times such as "2023-08-29T19:34:39Z" or "2023-08-29 19:34:39.678"
+/// by inspecting bytes alone, without allocating intermediate strings.
+/// Subseconds are truncated to milliseconds as in the fuzzy parser. Any other shape returns None
+pub(crate) fn canonical_iso_to_datetime(dt: &str) -> Option<NaiveDateTime> {
+  let bytes = dt.as_bytes();
+  let bytes = bytes.strip_suffix(b"Z").unwrap_or(bytes);
+  if bytes.len() < 19 {
+    return None;
+  }
+  let (main, fraction) = bytes.split_at(19);
+  if !matches!(main[10], b'T' | b' ') || main[13] != b':' || main[16] != b':' {
+    return None;
+  }
+  let millis = match fraction {
+    [] => 0,
+    [b'.', digits @ ..] if !digits.is_empty() && digits.len() <= 9 => {
+      read_ascii_digits(digits)?;
+      (0..3).fold(0, |acc, idx| acc * 10 + digits.get(idx).map_or(0, |b| (b - b'0') as u32))
+    }
+    _ => return None,
+  };
+  iso_date_from_bytes(&main[..10])?
+    .and_hms_milli_opt(read_ascii_digits(&main[11..13])?, read_ascii_digits(&main[14..16])?, read_ascii_digits(&main[17..19])?, millis)
+}
+
+/// Parse a canonical ISO 8601 date, "YYYY-MM-DD", without allocating. Any other shape returns None
+pub(crate) fn canonical_iso_to_date(dt: &str) -> Option<NaiveDate> {
+  iso_date_from_bytes(dt.as_bytes())
+}
+
+fn iso_date_from_bytes(bytes: &[u8]) -> Option<NaiveDate> {
+  if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+    return None;
+  }
+  NaiveDate::from_ymd_opt(read_ascii_digits(&bytes[0..4])? as i32, read_ascii_digits(&bytes[5..7])?, read_ascii_digits(&bytes[8..10])?)
+}
+
+fn read_ascii_digits(digits: &[u8]) -> Option<u32> {
+  digits.iter().try_fold(0u32, |acc, b| b.is_ascii_digit().then(|| acc * 10 + (b - b'0') as u32))
+}
+
+pub(crate) const WEEKDAY_NAMES: [&str; 7] = [
+  "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday",
+];
+
+/// Match an English weekday name or abbreviation of at least 3 letters, e.g. "Tue", "Thurs" or "Friday",
+/// case-insensitively and with an optional trailing period or comma
+pub(crate) fn alpha_weekday_to_index(token: &str) -> Option<Weekday> {
+  let name = token.trim_end_matches(['.', ',']).to_lowercase();
+  if name.chars().count() < 3 || !name.chars().all(char::is_alphabetic) {
+    return None;
+  }
+  WEEKDAY_NAMES.iter().position(|full_name| full_name.starts_with(&name)).and_then(|idx| Weekday::try_from(idx as u8).ok())
+}
+
+/// Match a user-defined weekday alias, e.g. "Dienstag" registered for Tuesday,
+/// case-insensitively and with an optional trailing period or comma
+pub(crate) fn weekday_alias_to_index(token: &str, aliases: &[(String, Weekday)]) -> Option<Weekday> {
+  let name = token.trim_end_matches(['.', ',']).to_lowercase();
+  aliases.iter().find(|(alias, _)| *alias == name).map(|&(_, weekday)| weekday)
+}
+
+/// Split a leading weekday name or alias, with or without a comma, from the rest of a date,
+/// e.g. "Tuesday, August 29, 2023" => (Weekday::Tue, "August 29, 2023")
+pub(crate) fn split_leading_weekday<'a>(text: &'a str, aliases: &[(String, Weekday)]) -> Option<(Weekday, &'a str)> {
+  let (first, rest) = text.trim().split_once(char::is_whitespace)?;
+  let weekday = alpha_weekday_to_index(first).or_else(|| weekday_alias_to_index(first, aliases))?;
+  let rest = rest.trim_start();
+  if rest.is_empty() {
+    None
+  } else {
+    Some((weekday, rest))
+  }
+}
+
+/// Remove whole words that carry no date information, e.g. "um" and "Uhr" in "29. August 2023 um 19:34 Uhr",
+/// matched case-insensitively. Returns None if no word is removed
+pub(crate) fn strip_filler_words(text: &str, filler_words: &[String]) -> Option<String> {
+  let is_filler = |word: &str| filler_words.iter().any(|filler| *filler == word.to_lowercase());
+  if !text.split_whitespace().any(is_filler) {
+    return None;
+  }
+  Some(text.split_whitespace().filter(|word| !is_filler(word)).collect::<Vec<_>>().join(" "))
+}
+
+/// Split a trailing parenthetical annotation such as a weekday, e.g. "2023-08-29 (Tue)",
+/// into the preceding text and the annotation without brackets
+pub(crate) fn split_parenthetical_suffix(text: &str) -> Option<(&str, &str)> {
+  let (base, annotation) = text.trim().strip_suffix(')')?.rsplit_once('(')?;
+  Some((base.trim(), annotation.trim()))
+}
+
+/// Match an English month name or abbreviation of at least 3 letters, e.g. "Aug", "Sept" or "August",
+/// case-insensitively and with an optional trailing period, returning the month number (1-12)
+pub(crate) fn alpha_month_to_index(token: &str) -> Option<u8> {
+  let name = token.trim_end_matches('.').to_lowercase();
+  if name.chars().count() < 3 || !name.chars().all(char::is_alphabetic) {
+    return None;
+  }
+  MONTH_NAMES.iter().position(|full_name| full_name.starts_with(&name)).map(|idx| idx as u8 + 1)
+    .or_else(|| FOREIGN_MONTH_NAMES.iter().find(|(full_name, _)| *full_name == name).map(|&(_, month)| month))
+}
+
+/// Markers following the year, month and day number in verbose or CJK dates, e.g. "2023 year 8 month 29 day" or "2023年8月29日"
+const DATE_UNIT_MARKERS: [(&str, usize); 6] = [("year", 0), ("month", 1), ("day", 2), ("年", 0), ("月", 1), ("日", 2)];
+
+/// Check whether a word marks a date unit, e.g. "year" or "月"
+pub(crate) fn is_date_unit_marker(word: &str) -> bool {
+  DATE_UNIT_MARKERS.iter().any(|(marker, _)| marker.eq_ignore_ascii_case(word))
+}
+
+/// Split a leading number followed by a date unit marker, e.g. "2023 year" or "8月", from the rest of a string,
+/// returning the number, the index of the unit from year to day and the remaining text
+fn split_unit_marked_number(text: &str) -> Option<(&str, usize, &str)> {
+  let text = text.trim_start();
+  let digits_end = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+  let (digits, after) = text.split_at(digits_end);
+  let after = after.trim_start();
+  // English markers must be whole words
+  let &(marker, idx) = DATE_UNIT_MARKERS.iter().find(|(marker, _)| {
+    after.get(..marker.len()).is_some_and(|word| word.eq_ignore_ascii_case(marker))
+      && !after[marker.len()..].starts_with(char::is_alphabetic)
+  })?;
+  if digits.is_empty() {
+    return None;
+  }
+  Some((digits, idx, after[marker.len()..].trim_start().trim_start_matches(',')))
+}
+
+/// Check whether a string starts with a number followed by a date unit marker, e.g. "2023 year" or "2023年"
+pub(crate) fn starts_with_unit_marked_number(text: &str) -> bool {
+  split_unit_marked_number(text).is_some()
+}
+
+/// Convert a date whose numbers are each followed by a unit marker at the start of a string, in any order,
+/// e.g. "2023 year 8 month 29 day" or "2023年8月29日 19:34", to an ISO-style date string plus any remaining text.
+/// The year must have 4 digits
+pub(crate) fn unit_marked_date_to_iso(text: &str) -> Option<(String, String)> {
+  let mut parts: [Option<u32>; 3] = [None; 3];
+  let mut rest = text.trim_start();
+  while let Some((digits, idx, after)) = split_unit_marked_number(rest) {
+    if parts[idx].is_some() || (idx == 0 && digits.len() != 4) {
+      return None;
+    }
+    parts[idx] = digits.parse().ok();
+    rest = after.trim_start();
+  }
+  let [Some(year), Some(month), Some(day)] = parts else {
+    return None;
+  };
+  let date = NaiveDate::from_ymd_opt(year as i32, month, day)?;
+  Some((date.format("%Y-%m-%d").to_string(), rest.to_string()))
+}
+
+/// Check whether a word is an article that may precede a day, e.g. "den" or "le"
+pub(crate) fn is_day_article(word: &str) -> bool {
+  DAY_ARTICLES.iter().any(|article| article.eq_ignore_ascii_case(word))
+}
+
+/// Strip a leading article before a day number, e.g. "den 29. August 2023" => "29. August 2023"
+pub(crate) fn strip_day_article(text: &str) -> Option<&str> {
+  let (first, rest) = text.trim().split_once(char::is_whitespace)?;
+  let rest = rest.trim_start();
+  if is_day_article(first) && rest.starts_with(|c: char| c.is_ascii_digit()) {
+    Some(rest)
+  } else {
+    None
+  }
+}
+
+/// Match a user-defined month alias as above, e.g. "Janurary" registered for January
+pub(crate) fn month_alias_to_index(token: &str, aliases: &[(String, u8)]) -> Option<u8> {
+  let name = token.trim_end_matches('.').to_lowercase();
+  aliases.iter().find(|(alias, _)| *alias == name).map(|&(_, month)| month)
+}
+
+/// Space out a leading date with a month name joined by hyphens, slashes or dots,
+/// e.g. "15-January-2023 19:34" => "15 January 2023 19:34" or "2023/Feb/03" => "2023 Feb 03",
+/// so it can be read as a named-month date
+pub(crate) fn space_joined_month_name_date(text: &str, month_aliases: &[(String, u8)]) -> Option<String> {
+  let trimmed = text.trim_start();
+  let date_end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+  let (date, rest) = trimmed.split_at(date_end);
+  let splitter = date.chars().find(|c| matches!(c, '-' | '/' | '.'))?;
+  let parts: Vec<&str> = date.split(splitter).collect();
+  let is_month = |part: &str| alpha_month_to_index(part).or_else(|| month_alias_to_index(part, month_aliases)).is_some();
+  let num_months = parts.iter().filter(|part| is_month(part)).count();
+  let others_are_digits = parts.iter().all(|part| is_month(part) || (!part.is_empty() && part.is_digits_only()));
+  if parts.len() == 3 && num_months == 1 && others_are_digits {
+    Some(format!("{}{}", parts.join(" "), rest))
+  } else {
+    None
+  }
+}
+
+/// Convert a date written with a month name at the start of a string, e.g. "29 August 2023" or
+/// "August 29, 2023 19:34", to an ISO-style date string plus any remaining text such as the time.
+/// The month, day and year may appear in any order. A number with an ordinal suffix, e.g. "29th", is the day,
+/// and a 4-digit number or one greater than 31 is the year. Otherwise a number directly after the month
+/// followed by a comma is the day, as in "Aug 5, 12", and failing that the year comes last unless
+/// year_first is set. Month aliases are tried after the standard names, and a 2-digit year is expanded
+/// by the given rule
+pub(crate) fn named_month_date_to_iso(text: &str, year_first: bool, month_aliases: &[(String, u8)], two_digit_years: TwoDigitYears) -> Option<(String, String)> {
+  let tokens: Vec<&str> = text.split_whitespace().collect();
+  let mut month: Option<u8> = None;
+  let mut month_pos = 0;
+  // numeric tokens with their positions, whether they are followed by a comma and whether they are ordinals
+  let mut numbers: Vec<(&str, usize, bool, bool)> = vec![];
+  let mut consumed = 0;
+  for (idx, &token) in tokens.iter().take(3).enumerate() {
+    let trimmed = token.trim_end_matches([',', '.']);
+    let core = strip_ordinal_suffix(trimmed);
+    if let Some(month_index) = alpha_month_to_index(core).or_else(|| month_alias_to_index(core, month_aliases)) {
+      if month.is_some() {
+        return None;
+      }
+      month = Some(month_index);
+      month_pos = idx;
+    } else if !core.is_empty() && core.len() <= 4 && core.is_digits_only() {
+      numbers.push((core, idx, token.ends_with(','), core != trimmed));
+    } else {
+      break;
+    }
+    consumed = idx + 1;
+  }
+  let month = month?;
+  let is_year = |n: &str| n.len() > 2 || n.parse::<u16>().unwrap_or(0) > 31;
+  let (year_str, day_str) = match numbers.as_slice() {
+    [(yr, _, _, false)] if is_year(yr) => (*yr, "1"),
+    [(first, first_pos, first_comma, first_ordinal), (second, _, _, second_ordinal)] => {
+      let day_marked_by_comma = *first_comma && *first_pos == month_pos + 1;
+      let first_is_year = match (first_ordinal, second_ordinal) {
+        (false, true) => true,
+        (true, false) => false,
+        _ => is_year(first) || (!is_year(second) && !day_marked_by_comma && year_first),
+      };
+      if first_is_year {
+        (*first, *second)
+      } else {
+        (*second, *first)
+      }
+    },
+    _ => return None,
+  };
+  let yr = expand_two_digit_year_by(year_str.parse::<u16>().ok()?, two_digit_years);
+  let day = day_str.parse::<u8>().ok()?;
+  if yr < 1000 || !(1..=31).contains(&day) {
+    return None;
+  }
+  Some((format!("{:04}-{:02}-{:02}", yr, month, day), tokens[consumed..].join(" ")))
+}
+
+/// Strip an English ordinal suffix from a day number, e.g. "29th" => "29", including Unicode superscript
+/// forms such as "1ˢᵗ" or "22ⁿᵈ" found in copy-pasted text. Other tokens are returned unchanged
+pub(crate) fn strip_ordinal_suffix(token: &str) -> &str {
+  let digits_end = token.find(|c: char| !c.is_ascii_digit()).unwrap_or(token.len());
+  let (digits, suffix) = token.split_at(digits_end);
+  let suffix: String = suffix.chars().map(|c| match c {
+    'ˢ' => 's',
+    'ᵗ' => 't',
+    'ⁿ' => 'n',
+    'ᵈ' => 'd',
+    'ʳ' => 'r',
+    'ʰ' => 'h',
+    _ => c.to_ascii_lowercase(),
+  }).collect();
+  // the French "1er" (premier) is the only ordinal with a suffix in dates, other days being cardinal
+  if !digits.is_empty() && (matches!(suffix.as_str(), "st" | "nd" | "rd" | "th") || (digits == "1" && suffix == "er")) {
+    digits
+  } else {
+    token
+  }
+}
+
+/// Join numbers grouped in thousands by a space, as in some European formats, e.g. "2 023" => "2023",
+/// so the group is not mistaken for a separate date or time. Only a whole token of 1 to 3 digits
+/// directly followed by a whole token of exactly 3 digits is joined. Returns None if there is no such group
+pub(crate) fn join_digit_groups(text: &str) -> Option<String> {
+  let tokens: Vec<&str> = text.split([' ', '\u{a0}', '\u{202f}']).collect();
+  let is_digit_group = |token: &str, lengths: std::ops::RangeInclusive<usize>| lengths.contains(&token.len()) && token.is_digits_only();
+  let mut joined = String::with_capacity(text.len());
+  let mut has_groups = false;
+  for (idx, &token) in tokens.iter().enumerate() {
+    if idx > 0 {
+      if is_digit_group(tokens[idx - 1], 1..=3) && is_digit_group(token, 3..=3) {
+        has_groups = true;
+      } else {
+        joined.push(' ');
+      }
+    }
+    joined.push_str(token);
+  }
+  if has_groups {
+    Some(joined)
+  } else {
+    None
+  }
+}
+
+/// extract the first year of a decade written as "1990s", "1990's" or "1990’s"
+pub(crate) fn decade_to_year(text: &str) -> Option<i32> {
+  let lc = text.trim().to_lowercase();
+  let digits = lc.strip_suffix('s')?;
+  let digits = digits.strip_suffix(['\'', '’']).unwrap_or(digits);
+  if digits.len() != 4 || !digits.is_digits_only() || !digits.ends_with('0') {
+    return None;
+  }
+  digits.parse::<i32>().ok()
+}
+
+/// Map a time keyword in the time position to a 24-hour time, e.g. "noon" => "12:00:00"
+/// EOD (end of day) resolves to the last whole second of the day and SOD/BOD (start or beginning of day) to midnight
+pub(crate) fn time_keyword_to_time(time_part: &str) -> Option<&'static str> {
+  match time_part.to_lowercase().as_str() {
+    "midnight" | "sod" | "bod" => Some("00:00:00"),
+    "noon" | "midday" => Some("12:00:00"),
+    "eod" => Some("23:59:59"),
+    _ => None,
+  }
+}
+
+/// Convert a time with h, m and s unit suffixes in that order, e.g. "19h34m39s" or the French "19h34",
+/// to a colon-separated time. Hours are required, while the minute and second suffixes are optional
+pub(crate) fn unit_suffixed_time_to_time(time_part: &str) -> Option<String> {
+  let lc = time_part.to_lowercase();
+  let (hrs, rest) = lc.split_once('h')?;
+  let (mins, rest) = rest.split_once('m').unwrap_or((rest, ""));
+  let secs = rest.strip_suffix('s').unwrap_or(rest);
+  let is_unit = |digits: &str, min_len: usize| (min_len..=2).contains(&digits.len()) && (digits.is_empty() || digits.is_digits_only());
+  if !is_unit(hrs, 1) || !is_unit(mins, 0) || !is_unit(secs, 0) {
+    return None;
+  }
+  let or_zero = |digits: &str| if digits.is_empty() { "00".to_string() } else { digits.to_string() };
+  Some(format!("{}:{}:{}", hrs, or_zero(mins), or_zero(secs)))
+}
+
+/// Split a trailing ±<n> adjustment with a d, h, m or s unit from a date-time, e.g. "+25h" in "2023-08-29T00:00:00Z+25h"
+/// The preceding text must end with a digit or a UTC designator
+pub(crate) fn split_trailing_adjustment(text: &str) -> Option<(&str, TimeDelta)> {
+  let text = text.trim();
+  let idx = text.rfind(['+', '-'])?;
+  let (base, adjustment) = text.split_at(idx);
+  let amount_unit = &adjustment[1..];
+  let unit = amount_unit.chars().next_back()?;
+  let digits = &amount_unit[..amount_unit.len() - unit.len_utf8()];
+  if digits.is_empty() || !digits.is_digits_only() || !base.ends_with(|c: char| c.is_ascii_digit() || c == 'Z' || c == 'z') {
+    return None;
+  }
+  let amount = digits.parse::<i64>().ok()?;
+  let amount = if adjustment.starts_with('-') { -amount } else { amount };
+  let delta = match unit.to_ascii_lowercase() {
+    'd' => TimeDelta::try_days(amount),
+    'h' => TimeDelta::try_hours(amount),
+    'm' => TimeDelta::try_minutes(amount),
+    's' => TimeDelta::try_seconds(amount),
+    _ => None,
+  }?;
+  Some((base, delta))
+}
+
+/// ISO 8601 allows a decimal fraction on the last time component when seconds are omitted,
+/// e.g. 19:34.5 => 19:34:30. Returns the time with whole seconds and the remaining milliseconds
+/// Two digits up to 59 after a dot are read as seconds with a mixed separator instead, e.g. 19:34.39
+pub(crate) fn fractional_minutes_to_time(time_part: &str) -> Option<(String, String)> {
+  let (hrs, mins_fraction) = time_part.split_once(':')?;
+  let (mins, fraction) = mins_fraction.split_once(['.', ','])?;
+  let is_unit = |n: &str| (1..=2).contains(&n.len()) && n.is_digits_only();
+  if !is_unit(hrs) || !is_unit(mins) || fraction.is_empty() || !fraction.is_digits_only() {
+    return None;
+  }
+  let is_dot_seconds = mins_fraction.as_bytes()[mins.len()] == b'.' && fraction.len() == 2 && fraction < "60";
+  if is_dot_seconds {
+    return None;
+  }
+  let minute_fraction = format!("0.{}", fraction).parse::<f64>().ok()?;
+  let total_ms = ((minute_fraction * 60_000.0).round() as u32).min(59_999);
+  Some((format!("{}:{}:{:02}", hrs, mins, total_ms / 1000), format!("{:03}", total_ms % 1000)))
+}
+
+/// convert the leading digits of a fraction of a second to nanoseconds according to their position,
+/// e.g. "7" => 700_000_000 and "07" => 70_000_000. Any trailing timezone indicator is ignored
+pub(crate) fn subsecond_digits_to_nanos(fraction: &str) -> u32 {
+  let digits: String = fraction.chars().take_while(|c| c.is_ascii_digit()).take(9).collect();
+  format!("{:0<9}", digits).parse::<u32>().unwrap_or(0)
+}
+
+/// Truncate nanoseconds to the given number of zero-padded fractional digits from 1 to 9,
+/// e.g. 678_901_234 => "678" with 3 digits or "678901" with 6
+pub(crate) fn format_subseconds(nanos: u32, digits: u8) -> String {
+  let digits = digits.clamp(1, 9);
+  format!("{:0width$}", nanos / 10u32.pow(9 - digits as u32), width = digits as usize)
+}
+
+/// Convert a year and a day of the year separated by a dot, as in astronomy data, e.g. "2023.241" => "2023-08-29".
+/// The day must have 3 digits or exceed 12, so "2023.08" is still read as a year and month
+pub(crate) fn dotted_ordinal_date_to_iso(date: &str) -> Option<String> {
+  let (year, day) = date.split_once('.')?;
+  if year.len() != 4 || !year.is_digits_only() || !(1..=3).contains(&day.len()) || !day.is_digits_only() {
+    return None;
+  }
+  let day_of_year = day.parse::<u32>().ok()?;
+  if day.len() < 3 && day_of_year <= 12 {
+    return None;
+  }
+  NaiveDate::from_yo_opt(year.parse().ok()?, day_of_year).map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Convert a date of two numbers, e.g. "08-2023" => "2023-08-01", with the missing day set by the anchor.
+/// A 4-digit number is the year on either side, otherwise two short numbers are read as set by two_part_dates
+pub(crate) fn two_part_date_to_iso(date: &str, splitter: char, order: DateOrder, two_part_dates: TwoPartDates, anchor: PeriodAnchor, two_digit_years: TwoDigitYears) -> Option<String> {
+  let (first, second) = date.split_once(splitter)?;
+  if ![first, second].iter().all(|part| (1..=4).contains(&part.len()) && part.is_digits_only()) {
+    return None;
+  }
+  let (first_num, second_num) = (first.parse::<u16>().ok()?, second.parse::<u16>().ok()?);
+  let (year, month, day) = match (first.len(), second.len(), two_part_dates) {
+    (4, 1..=2, _) => (first_num, second_num, None),
+    (1..=2, 4, _) => (second_num, first_num, None),
+    (1..=2, 1..=2, TwoPartDates::MonthYear) => (expand_two_digit_year_by(second_num, two_digit_years), first_num, None),
+    (1..=2, 1..=2, TwoPartDates::DayMonth(year)) if order == DateOrder::MDY => (year, first_num, Some(second_num)),
+    (1..=2, 1..=2, TwoPartDates::DayMonth(year)) => (year, second_num, Some(first_num)),
+    _ => return None,
+  };
+  if !(1..=12).contains(&month) {
+    return None;
+  }
+  let day = day.unwrap_or(if anchor == PeriodAnchor::End { days_in_month(year, month as u8) as u16 } else { 1 });
+  NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32).map(|date| date.format("%Y-%m-%d").to_string())
 }
 
+/// convert the digits after the decimal point of a fractional day (e.g. "5" in "2023-08-29.5")
+/// to a time of day (e.g. "12:00:00") and zero-padded milliseconds (e.g. "000")
+pub(crate) fn fraction_of_day_to_time(fraction: &str) -> Option<(String, String)> {
+  if fraction.is_empty() || !fraction.is_digits_only() {
+    return None;
+  }
+  let day_fraction = format!("0.{}", fraction).parse::<f64>().ok()?;
+  // a fraction that rounds up to a whole day (e.g. ".9999999999") stays on the last millisecond
+  let total_ms = ((day_fraction * 86_400_000.0).round() as u32).min(86_399_999);
+  let secs = total_ms / 1000;
+  let formatted_time = format!("{:02}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60);
+  Some((formatted_time, format!("{:03}", total_ms % 1000)))
+}
 
+/// Slice a compact all-digit date into its year, month and day, in that order, from the offsets of the date order
 pub fn digits_to_date_parts(date_str: &str, order: DateOrder) -> Vec<String> {
   let digits = date_str.strip_non_digits();
   let num_digits = digits.len() as u8;
@@ -150,6 +681,11 @@ pub fn digits_to_date_parts(date_str: &str, order: DateOrder) -> Vec<String> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use chrono::Timelike;
+
+  fn to_formatted_date_string(date_srr: &str,date_order: DateOrder, splitter: Option<char>) -> Option<String> {
+    to_formatted_date_string_anchored(date_srr, date_order, splitter, PeriodAnchor::Start, TwoDigitYears::default())
+  }
 
   #[test]
   fn test_expand_two_digit_year_stays_within_current_century_near_now() {
@@ -176,6 +712,40 @@ mod tests {
     assert_eq!(expanded % 100, yy, "expanded year should still end in the requested 2 digits");
   }
 
+  #[test]
+  fn test_two_part_date_to_iso() {
+    let two_part = |date: &str, order: DateOrder, two_part_dates: TwoPartDates| two_part_date_to_iso(date, '-', order, two_part_dates, PeriodAnchor::Start, TwoDigitYears::Pivot(69));
+    assert_eq!(two_part("2023-08", DateOrder::DMY, TwoPartDates::RequireYear), Some("2023-08-01".to_string()));
+    assert_eq!(two_part("08-2023", DateOrder::YMD, TwoPartDates::RequireYear), Some("2023-08-01".to_string()));
+    assert_eq!(two_part("08-23", DateOrder::YMD, TwoPartDates::RequireYear), None);
+    assert_eq!(two_part("08-23", DateOrder::YMD, TwoPartDates::MonthYear), Some("2023-08-01".to_string()));
+    assert_eq!(two_part("29-08", DateOrder::DMY, TwoPartDates::DayMonth(2024)), Some("2024-08-29".to_string()));
+    assert_eq!(two_part("08-29", DateOrder::MDY, TwoPartDates::DayMonth(2024)), Some("2024-08-29".to_string()));
+    assert_eq!(two_part("29-02", DateOrder::DMY, TwoPartDates::DayMonth(2023)), None);
+    assert_eq!(two_part("13-2023", DateOrder::YMD, TwoPartDates::RequireYear), None);
+    assert_eq!(two_part("2023-08-29", DateOrder::YMD, TwoPartDates::RequireYear), None);
+    assert_eq!(two_part_date_to_iso("02/2024", '/', DateOrder::DMY, TwoPartDates::RequireYear, PeriodAnchor::End, TwoDigitYears::Sliding), Some("2024-02-29".to_string()));
+  }
+
+  #[test]
+  fn test_expand_two_digit_year_by() {
+    assert_eq!(expand_two_digit_year_by(68, TwoDigitYears::Pivot(69)), 2068);
+    assert_eq!(expand_two_digit_year_by(69, TwoDigitYears::Pivot(69)), 1969);
+    assert_eq!(expand_two_digit_year_by(0, TwoDigitYears::Pivot(0)), 1900);
+    assert_eq!(expand_two_digit_year_by(99, TwoDigitYears::Pivot(100)), 2099);
+    assert_eq!(expand_two_digit_year_by(1968, TwoDigitYears::Pivot(69)), 1968);
+    assert_eq!(expand_two_digit_year_by(98, TwoDigitYears::Sliding), expand_two_digit_year(98));
+    let window = TwoDigitYears::Window { reference_year: 2023, years_ahead: 50 };
+    assert_eq!(expand_two_digit_year_by(95, window), 1995);
+    assert_eq!(expand_two_digit_year_by(10, window), 2010);
+    assert_eq!(expand_two_digit_year_by(73, window), 2073);
+    assert_eq!(expand_two_digit_year_by(74, window), 1974);
+    // the window may span a century
+    let window = TwoDigitYears::Window { reference_year: 1990, years_ahead: 10 };
+    assert_eq!(expand_two_digit_year_by(0, window), 2000);
+    assert_eq!(expand_two_digit_year_by(1, window), 1901);
+  }
+
   #[test]
   fn test_expand_two_digit_year_leaves_longer_years_untouched() {
     // Already-full years (>= 100, e.g. a genuine historical/astronomical date) must not
@@ -229,6 +799,221 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_unit_marked_date_to_iso() {
+    let iso = |date: &str, rest: &str| Some((date.to_string(), rest.to_string()));
+    assert_eq!(unit_marked_date_to_iso("2023 year 8 month 29 day"), iso("2023-08-29", ""));
+    assert_eq!(unit_marked_date_to_iso("29 Day 8 Month 2023 Year 19:34"), iso("2023-08-29", "19:34"));
+    assert_eq!(unit_marked_date_to_iso("2023年8月29日"), iso("2023-08-29", ""));
+    assert_eq!(unit_marked_date_to_iso("2023年 8月 29日 19:34"), iso("2023-08-29", "19:34"));
+    assert_eq!(unit_marked_date_to_iso("2023 year 8 month"), None);
+    assert_eq!(unit_marked_date_to_iso("23 year 8 month 29 day"), None);
+    assert_eq!(unit_marked_date_to_iso("2023 year 2 month 30 day"), None);
+    assert_eq!(unit_marked_date_to_iso("2023 years 8 month 29 day"), None);
+    assert!(starts_with_unit_marked_number("2023年"));
+    assert!(!starts_with_unit_marked_number("2023 yearly"));
+  }
+
+  #[test]
+  fn test_dotted_ordinal_date_to_iso() {
+    assert_eq!(dotted_ordinal_date_to_iso("2023.241").as_deref(), Some("2023-08-29"));
+    assert_eq!(dotted_ordinal_date_to_iso("2023.001").as_deref(), Some("2023-01-01"));
+    assert_eq!(dotted_ordinal_date_to_iso("2023.45").as_deref(), Some("2023-02-14"));
+    assert_eq!(dotted_ordinal_date_to_iso("2024.366").as_deref(), Some("2024-12-31"));
+    assert_eq!(dotted_ordinal_date_to_iso("2023.366"), None);
+    assert_eq!(dotted_ordinal_date_to_iso("2023.08"), None);
+    assert_eq!(dotted_ordinal_date_to_iso("2023.08.29"), None);
+  }
+
+  #[test]
+  fn test_alpha_month_to_index() {
+    assert_eq!(alpha_month_to_index("Jan"), Some(1));
+    assert_eq!(alpha_month_to_index("august"), Some(8));
+    assert_eq!(alpha_month_to_index("Sept."), Some(9));
+    assert_eq!(alpha_month_to_index("DEC"), Some(12));
+    assert_eq!(alpha_month_to_index("Ju"), None);
+    assert_eq!(alpha_month_to_index("Augusta"), None);
+    assert_eq!(alpha_month_to_index("août"), Some(8));
+    assert_eq!(alpha_month_to_index("Dezember"), Some(12));
+    assert_eq!(alpha_month_to_index("Juil"), None);
+  }
+
+  #[test]
+  fn test_month_alias_to_index() {
+    let aliases = vec![("janurary".to_string(), 1), ("sept".to_string(), 9)];
+    assert_eq!(month_alias_to_index("Janurary", &aliases), Some(1));
+    assert_eq!(month_alias_to_index("SEPT.", &aliases), Some(9));
+    assert_eq!(month_alias_to_index("Febuary", &aliases), None);
+  }
+
+  #[test]
+  fn test_space_joined_month_name_date() {
+    assert_eq!(space_joined_month_name_date("15-January-2023", &[]), Some("15 January 2023".to_string()));
+    assert_eq!(space_joined_month_name_date("2023/Feb/03 19:34", &[]), Some("2023 Feb 03 19:34".to_string()));
+    assert_eq!(space_joined_month_name_date("15.Jan.2023", &[]), Some("15 Jan 2023".to_string()));
+    assert_eq!(space_joined_month_name_date("15 January 2023", &[]), None);
+    assert_eq!(space_joined_month_name_date("2023-08-29", &[]), None);
+    assert_eq!(space_joined_month_name_date("15-Jan-Feb", &[]), None);
+  }
+
+  #[test]
+  fn test_named_month_date_to_iso() {
+    assert_eq!(named_month_date_to_iso("August 29, 2023", false, &[], TwoDigitYears::Sliding), Some(("2023-08-29".to_string(), "".to_string())));
+    assert_eq!(named_month_date_to_iso("29 August 2023 19:34", false, &[], TwoDigitYears::Sliding), Some(("2023-08-29".to_string(), "19:34".to_string())));
+    assert_eq!(named_month_date_to_iso("August 2023", false, &[], TwoDigitYears::Sliding), Some(("2023-08-01".to_string(), "".to_string())));
+    assert_eq!(named_month_date_to_iso("August 29", false, &[], TwoDigitYears::Sliding), None);
+    assert_eq!(named_month_date_to_iso("2023-08-29", false, &[], TwoDigitYears::Sliding), None);
+  }
+
+  #[test]
+  fn test_canonical_iso_to_datetime() {
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_milli_opt(19, 34, 39, 678).unwrap();
+    assert_eq!(canonical_iso_to_datetime("2023-08-29T19:34:39.678Z"), Some(expected));
+    assert_eq!(canonical_iso_to_datetime("2023-08-29 19:34:39.678901"), Some(expected));
+    assert_eq!(canonical_iso_to_datetime("2023-08-29T19:34:39.6"), Some(expected.with_nanosecond(600_000_000).unwrap()));
+    assert_eq!(canonical_iso_to_datetime("2023-08-29T19:34:39Z"), Some(expected.with_nanosecond(0).unwrap()));
+    // anything else is left to the fuzzy parser
+    assert_eq!(canonical_iso_to_datetime("2023-08-29T19:34"), None);
+    assert_eq!(canonical_iso_to_datetime("2023-08-29T19:34:39."), None);
+    assert_eq!(canonical_iso_to_datetime("2023-08-29T19:34:39+02:00"), None);
+    assert_eq!(canonical_iso_to_datetime("2023-02-30T19:34:39"), None);
+    assert_eq!(canonical_iso_to_datetime("29-08-2023T19:34:39"), None);
+  }
+
+  #[test]
+  fn test_weekdays_and_parenthetical_suffixes() {
+    assert_eq!(alpha_weekday_to_index("Tue"), Some(Weekday::Tue));
+    assert_eq!(alpha_weekday_to_index("thurs."), Some(Weekday::Thu));
+    assert_eq!(alpha_weekday_to_index("Sunday,"), Some(Weekday::Sun));
+    assert_eq!(alpha_weekday_to_index("Mo"), None);
+    assert_eq!(split_parenthetical_suffix("2023-08-29 (Tue) "), Some(("2023-08-29", "Tue")));
+    assert_eq!(split_parenthetical_suffix("2023-08-29"), None);
+  }
+
+  #[test]
+  fn test_strip_ordinal_suffix() {
+    assert_eq!(strip_ordinal_suffix("29th"), "29");
+    assert_eq!(strip_ordinal_suffix("2ND"), "2");
+    assert_eq!(strip_ordinal_suffix("1ˢᵗ"), "1");
+    assert_eq!(strip_ordinal_suffix("23ʳᵈ"), "23");
+    assert_eq!(strip_ordinal_suffix("29"), "29");
+    assert_eq!(strip_ordinal_suffix("Aug"), "Aug");
+    assert_eq!(strip_ordinal_suffix("29x"), "29x");
+  }
+
+  #[test]
+  fn test_join_digit_groups() {
+    assert_eq!(join_digit_groups("2 023"), Some("2023".to_string()));
+    assert_eq!(join_digit_groups("29 Aug 2\u{a0}023 19:34"), Some("29 Aug 2023 19:34".to_string()));
+    // a 4-digit year or a time is not a group
+    assert_eq!(join_digit_groups("29 08 2023"), None);
+    assert_eq!(join_digit_groups("2023-08-29 734 pm"), None);
+    assert_eq!(join_digit_groups("2023-08-29 19:34"), None);
+  }
+
+  #[test]
+  fn test_split_leading_weekday() {
+    assert_eq!(split_leading_weekday("Tuesday, August 29, 2023", &[]), Some((Weekday::Tue, "August 29, 2023")));
+    assert_eq!(split_leading_weekday("tue 29.08.2023", &[]), Some((Weekday::Tue, "29.08.2023")));
+    assert_eq!(split_leading_weekday("August 29, 2023", &[]), None);
+    assert_eq!(split_leading_weekday("Tuesday", &[]), None);
+    let aliases = [("dienstag".to_string(), Weekday::Tue)];
+    assert_eq!(split_leading_weekday("Dienstag, 29. August 2023", &aliases), Some((Weekday::Tue, "29. August 2023")));
+  }
+
+  #[test]
+  fn test_unit_suffixed_time_to_time() {
+    assert_eq!(unit_suffixed_time_to_time("19h34m39s"), Some("19:34:39".to_string()));
+    assert_eq!(unit_suffixed_time_to_time("19h34"), Some("19:34:00".to_string()));
+    assert_eq!(unit_suffixed_time_to_time("7H"), Some("7:00:00".to_string()));
+    assert_eq!(unit_suffixed_time_to_time("19:34"), None);
+    assert_eq!(unit_suffixed_time_to_time("h34"), None);
+    assert_eq!(unit_suffixed_time_to_time("19h34s"), None);
+  }
+
+  #[test]
+  fn test_split_trailing_adjustment() {
+    assert_eq!(split_trailing_adjustment("2023-08-29T00:00:00Z+25h"), Some(("2023-08-29T00:00:00Z", TimeDelta::hours(25))));
+    assert_eq!(split_trailing_adjustment("2023-08-29 19:34-90m"), Some(("2023-08-29 19:34", TimeDelta::minutes(-90))));
+    assert_eq!(split_trailing_adjustment("2023-08-29+2d"), Some(("2023-08-29", TimeDelta::days(2))));
+    assert_eq!(split_trailing_adjustment("2023-08-29"), None);
+    assert_eq!(split_trailing_adjustment("2023-08-29T19:34:39+05:30"), None);
+    assert_eq!(split_trailing_adjustment("2023-08-29T19:34:39+1w"), None);
+    // units are single ASCII letters
+    assert_eq!(split_trailing_adjustment("2023-08-29+é"), None);
+    assert_eq!(split_trailing_adjustment("2023-08-29+2é"), None);
+    assert_eq!(split_trailing_adjustment("2023-08-29+"), None);
+  }
+
+  #[test]
+  fn test_decade_to_year() {
+    assert_eq!(decade_to_year("1990s"), Some(1990));
+    assert_eq!(decade_to_year("1990's"), Some(1990));
+    assert_eq!(decade_to_year("2020’S"), Some(2020));
+    assert_eq!(decade_to_year("1995s"), None);
+    assert_eq!(decade_to_year("90s"), None);
+    assert_eq!(decade_to_year("2020"), None);
+  }
+
+  #[test]
+  fn test_fractional_minutes() {
+    assert_eq!(fractional_minutes_to_time("19:34.5"), Some(("19:34:30".to_string(), "000".to_string())));
+    assert_eq!(fractional_minutes_to_time("19:34,25"), Some(("19:34:15".to_string(), "000".to_string())));
+    assert_eq!(fractional_minutes_to_time("19:34.123"), Some(("19:34:07".to_string(), "380".to_string())));
+    assert_eq!(fractional_minutes_to_time("19:34:39.5"), None);
+    assert_eq!(fractional_minutes_to_time("19:34.39"), None);
+    assert_eq!(fuzzy_to_formatted_time_parts("19:34.5", "", None, true, '.', 3), Some(("19:34:30".to_string(), ".000Z".to_string())));
+    assert_eq!(fuzzy_to_formatted_time_parts("1934", "", None, true, '.', 3), Some(("19:34:00".to_string(), ".000Z".to_string())));
+    assert_eq!(fuzzy_to_formatted_time_parts("193439", "", None, false, '.', 3), Some(("19:34:39".to_string(), "".to_string())));
+    assert_eq!(fuzzy_to_formatted_time_parts("19345", "", None, false, '.', 3), None);
+    assert_eq!(fuzzy_to_formatted_time_parts("19h34", "", None, false, '.', 3), Some(("19:34:00".to_string(), "".to_string())));
+    assert_eq!(fuzzy_to_formatted_time_parts("19x34", "", None, false, '.', 3), None);
+  }
+
+  #[test]
+  fn test_format_subseconds() {
+    assert_eq!(format_subseconds(678_901_234, 3), "678");
+    assert_eq!(format_subseconds(678_901_234, 6), "678901");
+    assert_eq!(format_subseconds(678_901_234, 9), "678901234");
+    assert_eq!(format_subseconds(70_000_000, 3), "070");
+    assert_eq!(fuzzy_to_formatted_time_parts("19:34:39", "678901234+05:30", None, true, '.', 9), Some(("19:34:39".to_string(), ".678901234+05:30".to_string())));
+  }
+
+  #[test]
+  fn test_subsecond_digits_to_nanos() {
+    assert_eq!(subsecond_digits_to_nanos("7"), 700_000_000);
+    assert_eq!(subsecond_digits_to_nanos("07"), 70_000_000);
+    assert_eq!(subsecond_digits_to_nanos("678Z"), 678_000_000);
+    assert_eq!(subsecond_digits_to_nanos(""), 0);
+  }
+
+  #[test]
+  fn test_fraction_of_day_to_time() {
+    assert_eq!(fraction_of_day_to_time("5"), Some(("12:00:00".to_string(), "000".to_string())));
+    assert_eq!(fraction_of_day_to_time("25"), Some(("06:00:00".to_string(), "000".to_string())));
+    assert_eq!(fraction_of_day_to_time("75"), Some(("18:00:00".to_string(), "000".to_string())));
+    // never rolls over into the following day
+    assert_eq!(fraction_of_day_to_time("9999999999"), Some(("23:59:59".to_string(), "999".to_string())));
+    assert_eq!(fraction_of_day_to_time("5Z"), None);
+  }
+
+  #[test]
+  fn test_compact_dates_reject_non_digits() {
+    assert_eq!(to_formatted_date_string("20230829", DateOrder::YMD, None), Some("2023-08-29".to_string()));
+    assert_eq!(to_formatted_date_string("2023ab0829", DateOrder::YMD, None), None);
+    assert_eq!(to_formatted_date_string("2023ab08ab29", DateOrder::YMD, None), None);
+    assert_eq!(to_formatted_date_string("2023_08_29", DateOrder::YMD, None), None);
+  }
+
+  #[test]
+  fn test_end_anchored_partial_dates() {
+    assert_eq!(to_formatted_date_string_anchored("2023-08", DateOrder::YMD, Some('-'), PeriodAnchor::End, TwoDigitYears::Sliding), Some("2023-08-31".to_string()));
+    assert_eq!(to_formatted_date_string_anchored("2024-2", DateOrder::YMD, Some('-'), PeriodAnchor::End, TwoDigitYears::Sliding), Some("2024-02-29".to_string()));
+    assert_eq!(to_formatted_date_string_anchored("2023", DateOrder::YMD, Some('-'), PeriodAnchor::End, TwoDigitYears::Sliding), Some("2023-12-31".to_string()));
+    // complete dates are unaffected
+    assert_eq!(to_formatted_date_string_anchored("2023-08-15", DateOrder::YMD, Some('-'), PeriodAnchor::End, TwoDigitYears::Sliding), Some("2023-08-15".to_string()));
+  }
+
   #[test]
   fn test_year_and_month_only_partial_dates_are_unaffected() {
     // The crate's original use case (a full 4-digit year with just year+month known,
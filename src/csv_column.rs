@@ -0,0 +1,50 @@
+use chrono::NaiveDateTime;
+use crate::{detect::detect_conclusive_date_format, fuzzy_to_datetime, DateOptions};
+
+/// Parse a date column of CSV records in a stream, detecting the date format once from a buffered
+/// sample of the first sample_size records and then parsing every record with it, e.g. for large imports.
+/// Each item is None if the record lacks the column or its value is not a date.
+/// If the sample does not settle the date order, each value is guessed in turn
+pub fn parse_csv_date_column<I, R, S>(records: I, col: usize, sample_size: usize) -> impl Iterator<Item = Option<NaiveDateTime>>
+where
+  I: IntoIterator<Item = R>,
+  R: AsRef<[S]>,
+  S: AsRef<str>,
+{
+  let mut records = records.into_iter();
+  let sample: Vec<R> = records.by_ref().take(sample_size).collect();
+  let date_opts = detect_conclusive_date_format(&sample, |record| record.as_ref().get(col).map(|cell| cell.as_ref().to_string()))
+    .unwrap_or_else(DateOptions::auto);
+  sample.into_iter().chain(records).map(move |record| {
+    let cell = record.as_ref().get(col)?;
+    fuzzy_to_datetime(cell.as_ref(), Some(date_opts.clone()), None).ok()
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_parse_csv_date_column() {
+    let records = vec![
+      vec!["1", "05/06/2023"],
+      vec!["2", "29/08/2023 19:34"],
+      vec!["3", "01/02/2023"],
+      vec!["4"],
+      vec!["5", "n/a"],
+    ];
+    // the 2nd record in the sample shows the dates are day-first
+    let parsed: Vec<Option<NaiveDateTime>> = parse_csv_date_column(records.clone(), 1, 2).collect();
+    assert_eq!(parsed.len(), 5);
+    assert_eq!(parsed[0], NaiveDate::from_ymd_opt(2023, 6, 5).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(parsed[1], NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(19, 34, 0));
+    assert_eq!(parsed[2], NaiveDate::from_ymd_opt(2023, 2, 1).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(parsed[3], None);
+    assert_eq!(parsed[4], None);
+    // records are consumed lazily after the sample
+    let mut stream = parse_csv_date_column(records.into_iter().cycle(), 1, 2);
+    assert_eq!(stream.nth(7).flatten(), NaiveDate::from_ymd_opt(2023, 2, 1).unwrap().and_hms_opt(0, 0, 0));
+  }
+}
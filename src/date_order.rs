@@ -1,4 +1,6 @@
 use std::ops::Range;
+use chrono::{NaiveDate, Weekday};
+use crate::{guess::surmise_date_order_and_splitter_by_priority, is_sentinel_date, Clock, NumberLocale, PeriodAnchor};
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,22 +50,514 @@ impl DateOrder {
 }
 
 
+/// How dates with only two numbers are read. A 4-digit number is always the year, whichever side it is on,
+/// e.g. "2023-08" and "08-2023" are both August 2023
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoPartDates {
+  /// Two short numbers are not a date, as they have no 4-digit year
+  RequireYear,
+  /// Two short numbers are a month and 2-digit year, e.g. "08/23" for August 2023
+  MonthYear,
+  /// Two short numbers are a day and month in the given year, e.g. "29/08" for 29 August,
+  /// or a month and day if the date order is MDY
+  DayMonth(u16),
+}
+
+/// How 2-digit years are expanded to 4 digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TwoDigitYears {
+  /// Up to 20 years ahead of the current year
+  Sliding,
+  /// Below the pivot in the 2000s, otherwise in the 1900s
+  Pivot(u8),
+  /// In the 100 years ending the given number of years after the reference year
+  Window { reference_year: u16, years_ahead: u8 },
+}
+
+/// 2-digit years below 69 are in the 2000s by default, as in POSIX strptime
+impl Default for TwoDigitYears {
+  fn default() -> Self {
+    TwoDigitYears::Pivot(69)
+  }
+}
+
+/// The default maximum length of input strings in bytes
+const DEFAULT_MAX_INPUT_LEN: usize = 64;
+
 /// Options for parsing the date component of strings
-pub struct DateOptions(pub DateOrder, pub Option<char>);
+/// The date order and splitter may be fixed or, via DateOptions::auto(), surmised from each date string
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateOptions {
+  order: DateOrder,
+  splitter: Option<char>,
+  guess: bool,
+  fractional_days: bool,
+  subsecond_separator: char,
+  subsecond_digits: u8,
+  preserve_date_only: bool,
+  period_anchor: PeriodAnchor,
+  valid_range: Option<(NaiveDate, NaiveDate)>,
+  splitter_priority: Option<Vec<char>>,
+  validate_weekday: bool,
+  clock: Clock,
+  strict_ascii_digits: bool,
+  strict_leading_zeros: bool,
+  number_locale: NumberLocale,
+  reject_sentinels: bool,
+  adjustments: bool,
+  lenient_time: bool,
+  compact_order_fallback: bool,
+  guess_fallback: bool,
+  month_aliases: Vec<(String, u8)>,
+  weekday_aliases: Vec<(String, Weekday)>,
+  filler_words: Vec<String>,
+  require_t_separator: bool,
+  two_digit_years: TwoDigitYears,
+  two_part_dates: TwoPartDates,
+  consistent_splitter: bool,
+  max_input_len: usize,
+  normalize_to_utc: bool,
+}
 
 impl DateOptions {
+  /// instantiate options with a fixed date order and optional split character
+  /// None implies a fixed-width all-digit date such as 18761208
+  pub fn new(order: DateOrder, splitter: Option<char>) -> Self {
+    DateOptions {
+      order,
+      splitter,
+      guess: false,
+      fractional_days: false,
+      subsecond_separator: '.',
+      subsecond_digits: 3,
+      preserve_date_only: false,
+      period_anchor: PeriodAnchor::Start,
+      valid_range: None,
+      splitter_priority: None,
+      validate_weekday: false,
+      clock: Clock::Auto,
+      strict_ascii_digits: false,
+      strict_leading_zeros: false,
+      number_locale: NumberLocale::Any,
+      reject_sentinels: false,
+      adjustments: false,
+      lenient_time: false,
+      compact_order_fallback: false,
+      guess_fallback: false,
+      month_aliases: vec![],
+      weekday_aliases: vec![],
+      filler_words: vec![],
+      require_t_separator: false,
+      two_digit_years: TwoDigitYears::default(),
+      two_part_dates: TwoPartDates::RequireYear,
+      consistent_splitter: false,
+      max_input_len: DEFAULT_MAX_INPUT_LEN,
+      normalize_to_utc: false,
+    }
+  }
+
+  /// Guess the date order and splitter from each date string,
+  /// while still honouring any other options set on this object
+  pub fn auto() -> Self {
+    DateOptions {
+      guess: true,
+      ..Self::default()
+    }
+  }
+
   pub fn order(&self) -> DateOrder {
-    self.0
+    self.order
   }
 
   pub fn splitter(&self) -> Option<char> {
-    self.1
+    self.splitter
+  }
+
+  /// true if the date order and splitter should be surmised from each date string
+  pub fn is_auto(&self) -> bool {
+    self.guess
+  }
+
+  /// Interpret a trailing .<digits> after a full date as a fraction of a day,
+  /// e.g. 2023-08-29.5 => 2023-08-29T12:00:00
+  pub fn with_fractional_days(mut self, enabled: bool) -> Self {
+    self.fractional_days = enabled;
+    self
+  }
+
+  pub fn fractional_days(&self) -> bool {
+    self.fractional_days
+  }
+
+  /// Set the character written before the fractional seconds in date-time output,
+  /// e.g. ',' for 19:34:39,000. Defaults to '.'
+  pub fn with_subsecond_separator(mut self, separator: char) -> Self {
+    self.subsecond_separator = separator;
+    self
+  }
+
+  pub fn subsecond_separator(&self) -> char {
+    self.subsecond_separator
+  }
+
+  /// Set the number of fractional second digits in date-time output from 1 to 9, e.g. 6 for microseconds
+  /// or 9 to keep nanoseconds from logs. fuzzy_to_datetime then keeps the same precision. Defaults to 3
+  pub fn with_subsecond_digits(mut self, digits: u8) -> Self {
+    self.subsecond_digits = digits.clamp(1, 9);
+    self
+  }
+
+  pub fn subsecond_digits(&self) -> u8 {
+    self.subsecond_digits
+  }
+
+  /// Output date-only source strings as dates even from date-time functions,
+  /// rather than implying a midnight UTC instant that wasn't in the data
+  pub fn with_preserve_date_only(mut self, enabled: bool) -> Self {
+    self.preserve_date_only = enabled;
+    self
+  }
+
+  pub fn preserve_date_only(&self) -> bool {
+    self.preserve_date_only
+  }
+
+  /// Resolve partial dates such as 2023-08 or 2023-Q3 to the start (default) or end of the period
+  pub fn with_period_anchor(mut self, anchor: PeriodAnchor) -> Self {
+    self.period_anchor = anchor;
+    self
+  }
+
+  pub fn period_anchor(&self) -> PeriodAnchor {
+    self.period_anchor
+  }
+
+  /// Reject dates outside an inclusive range, e.g. to rule out birth dates in the future
+  pub fn with_valid_range(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+    self.valid_range = Some((start, end));
+    self
+  }
+
+  pub fn valid_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+    self.valid_range
+  }
+
+  /// Reject the sentinel date 9999-12-31, which stands for "never" in many business systems,
+  /// rather than returning it as a literal date. See is_sentinel_date
+  pub fn with_reject_sentinels(mut self, enabled: bool) -> Self {
+    self.reject_sentinels = enabled;
+    self
+  }
+
+  pub fn reject_sentinels(&self) -> bool {
+    self.reject_sentinels
+  }
+
+  /// check a formatted YYYY-MM-DD date against the valid range, if any, and any rejected sentinel
+  pub(crate) fn is_in_valid_range(&self, formatted_date: &str) -> bool {
+    if self.valid_range.is_some() || self.reject_sentinels {
+      NaiveDate::parse_from_str(formatted_date, "%Y-%m-%d").is_ok_and(|date| self.is_date_in_valid_range(&date))
+    } else {
+      true
+    }
+  }
+
+  pub(crate) fn is_date_in_valid_range(&self, date: &NaiveDate) -> bool {
+    self.valid_range.is_none_or(|(start, end)| *date >= start && *date <= end)
+      && !(self.reject_sentinels && is_sentinel_date(date))
+  }
+
+  /// canonical ISO date-times may bypass the fuzzy parser if these options would read them unchanged
+  /// and they are within the maximum input length
+  pub(crate) fn accepts_canonical_iso(&self, dt: &str) -> bool {
+    dt.len() <= self.max_input_len
+      && (self.guess || (self.order == DateOrder::YMD && self.splitter == Some('-'))) && !self.fractional_days && !self.require_t_separator && self.subsecond_digits == 3
+      && self.clock != Clock::Clock12 && self.number_locale != NumberLocale::DecimalComma
+  }
+
+  /// When guessing, prefer date splitters in this order if more than one appears,
+  /// e.g. &['.', '-', '/'] to make '.' win over '-'. By default the first separator found wins
+  pub fn with_splitter_priority(mut self, separators: &[char]) -> Self {
+    self.splitter_priority = Some(separators.to_vec());
+    self
+  }
+
+  pub fn splitter_priority(&self) -> Option<&[char]> {
+    self.splitter_priority.as_deref()
+  }
+
+  /// Reject dates whose annotated weekday, e.g. "(Wed)" in "2023-08-29 (Wed)", doesn't match the date
+  pub fn with_validate_weekday(mut self, enabled: bool) -> Self {
+    self.validate_weekday = enabled;
+    self
+  }
+
+  pub fn validate_weekday(&self) -> bool {
+    self.validate_weekday
+  }
+
+  /// Set whether times are read on a 12-hour or 24-hour clock, or by AM/PM markers when present (default)
+  pub fn with_clock(mut self, clock: Clock) -> Self {
+    self.clock = clock;
+    self
+  }
+
+  pub fn clock(&self) -> Clock {
+    self.clock
+  }
+
+  /// Reject any input with non-ASCII digits, such as Arabic-Indic or fullwidth digits,
+  /// to guard against confusable characters
+  pub fn with_strict_ascii_digits(mut self, enabled: bool) -> Self {
+    self.strict_ascii_digits = enabled;
+    self
+  }
+
+  pub fn strict_ascii_digits(&self) -> bool {
+    self.strict_ascii_digits
+  }
+
+  /// Reject date components with more leading zeros than their width allows, e.g. the month in 2023-008-29,
+  /// as a sign of corrupted data. By default these are read leniently, i.e. "008" as 8
+  pub fn with_strict_leading_zeros(mut self, enabled: bool) -> Self {
+    self.strict_leading_zeros = enabled;
+    self
+  }
+
+  pub fn strict_leading_zeros(&self) -> bool {
+    self.strict_leading_zeros
+  }
+
+  /// Set whether a decimal point, a decimal comma or either (default) separates subseconds from seconds
+  pub fn with_number_locale(mut self, number_locale: NumberLocale) -> Self {
+    self.number_locale = number_locale;
+    self
+  }
+
+  pub fn number_locale(&self) -> NumberLocale {
+    self.number_locale
+  }
+
+  /// Apply a trailing adjustment in days, hours, minutes or seconds to a date-time,
+  /// e.g. "2023-08-29T19:34:39Z+1h" as written by some monitoring tools
+  pub fn with_adjustments(mut self, enabled: bool) -> Self {
+    self.adjustments = enabled;
+    self
+  }
+
+  pub fn adjustments(&self) -> bool {
+    self.adjustments
+  }
+
+  /// Replace an invalid time, e.g. 25:99, with midnight in date-time output and keep the date,
+  /// rather than rejecting the whole date-time
+  pub fn with_lenient_time(mut self, enabled: bool) -> Self {
+    self.lenient_time = enabled;
+    self
+  }
+
+  pub fn lenient_time(&self) -> bool {
+    self.lenient_time
+  }
+
+  /// Treat the configured date order and splitter as a hint rather than a mandate, so a date that cannot be read
+  /// with them, e.g. 29-08-2023 with YMD, is guessed instead. By default the configured format is strictly honoured
+  pub fn with_guess_fallback(mut self, enabled: bool) -> Self {
+    self.guess_fallback = enabled;
+    self
+  }
+
+  pub fn guess_fallback(&self) -> bool {
+    self.guess_fallback
+  }
+
+  /// If a compact all-digit date such as 13071998 is invalid in the configured order,
+  /// try DMY, MDY and YMD in turn and accept the first valid date
+  pub fn with_compact_order_fallback(mut self, enabled: bool) -> Self {
+    self.compact_order_fallback = enabled;
+    self
+  }
+
+  pub fn compact_order_fallback(&self) -> bool {
+    self.compact_order_fallback
+  }
+
+  /// Register extra month names, such as typos or local abbreviations, e.g. &[("Janurary", 1)].
+  /// Aliases are matched case-insensitively and with an optional trailing period,
+  /// after the standard English names. Months outside 1-12 are ignored
+  pub fn with_month_aliases(mut self, aliases: &[(&str, u8)]) -> Self {
+    self.month_aliases = aliases.iter()
+      .filter(|(_, month)| (1..=12).contains(month))
+      .map(|&(name, month)| (name.trim_end_matches('.').to_lowercase(), month))
+      .collect();
+    self
+  }
+
+  pub fn month_aliases(&self) -> &[(String, u8)] {
+    &self.month_aliases
+  }
+
+  /// Register extra weekday names, such as those of other languages, e.g. &[("Dienstag", Weekday::Tue)].
+  /// Aliases are matched case-insensitively and with an optional trailing period or comma
+  pub fn with_weekday_aliases(mut self, aliases: &[(&str, Weekday)]) -> Self {
+    self.weekday_aliases = aliases.iter()
+      .map(|&(name, weekday)| (name.trim_end_matches(['.', ',']).to_lowercase(), weekday))
+      .collect();
+    self
+  }
+
+  pub fn weekday_aliases(&self) -> &[(String, Weekday)] {
+    &self.weekday_aliases
+  }
+
+  /// Ignore whole words that carry no date information, e.g. &["um", "Uhr"] for "29. August 2023 um 19:34 Uhr".
+  /// Words are matched case-insensitively
+  pub fn with_filler_words(mut self, words: &[&str]) -> Self {
+    self.filler_words = words.iter().map(|word| word.to_lowercase()).collect();
+    self
+  }
+
+  pub fn filler_words(&self) -> &[String] {
+    &self.filler_words
+  }
+
+  /// Reject date-times with a space rather than a 'T' between the date and time, as in strict ISO 8601,
+  /// e.g. "2023-08-29 19:34:39". Dates without a time are unaffected
+  pub fn with_require_t_separator(mut self, enabled: bool) -> Self {
+    self.require_t_separator = enabled;
+    self
+  }
+
+  pub fn require_t_separator(&self) -> bool {
+    self.require_t_separator
+  }
+
+  /// Expand 2-digit years below the pivot to 20xx and others to 19xx, e.g. with 50, 28/02/49 => 2049-02-28
+  /// and 28/02/50 => 1950-02-28. Pivots above 100 are capped at 100.
+  /// The default pivot is 69, as in POSIX strptime, so 28/02/68 => 2068-02-28 and 28/02/69 => 1969-02-28
+  pub fn with_pivot(mut self, pivot: u8) -> Self {
+    self.two_digit_years = TwoDigitYears::Pivot(pivot.min(100));
+    self
+  }
+
+  pub fn pivot(&self) -> Option<u8> {
+    match self.two_digit_years {
+      TwoDigitYears::Pivot(pivot) => Some(pivot),
+      _ => None,
+    }
+  }
+
+  /// Expand 2-digit years to the latest year no more than years_ahead after the reference year,
+  /// e.g. with 2023 and 50, "95" => 1995 and "10" => 2010, as an alternative to a fixed pivot.
+  /// Passing the reference year keeps results stable, unlike the sliding window relative to today
+  pub fn with_reference_year(mut self, reference_year: u16, years_ahead: u8) -> Self {
+    self.two_digit_years = TwoDigitYears::Window { reference_year, years_ahead: years_ahead.min(99) };
+    self
+  }
+
+  pub fn reference_year(&self) -> Option<u16> {
+    match self.two_digit_years {
+      TwoDigitYears::Window { reference_year, .. } => Some(reference_year),
+      _ => None,
+    }
+  }
+
+  /// Expand 2-digit years to the current century if up to 20 years ahead of the current year,
+  /// otherwise to the previous century, rather than with a fixed pivot
+  pub fn with_sliding_window(mut self) -> Self {
+    self.two_digit_years = TwoDigitYears::Sliding;
+    self
+  }
+
+  pub(crate) fn two_digit_years(&self) -> TwoDigitYears {
+    self.two_digit_years
+  }
+
+  /// Read dates of two short numbers, e.g. "08/23", as a month and year or a day and month
+  /// rather than rejecting them for lack of a 4-digit year
+  pub fn with_two_part_dates(mut self, two_part_dates: TwoPartDates) -> Self {
+    self.two_part_dates = two_part_dates;
+    self
+  }
+
+  pub fn two_part_dates(&self) -> TwoPartDates {
+    self.two_part_dates
+  }
+
+  /// Reject dates mixing different splitters, e.g. 2023-08/29, as malformed
+  /// rather than reading them with the first or configured splitter
+  pub fn with_consistent_splitter(mut self, enabled: bool) -> Self {
+    self.consistent_splitter = enabled;
+    self
+  }
+
+  pub fn consistent_splitter(&self) -> bool {
+    self.consistent_splitter
+  }
+
+  /// Reject inputs longer than this many bytes without scanning them, to bound the work spent on untrusted input.
+  /// Defaults to 64, ample for date-times with subseconds and offsets, but may be raised for long trailing text
+  pub fn with_max_input_len(mut self, max_len: usize) -> Self {
+    self.max_input_len = max_len;
+    self
+  }
+
+  pub fn max_input_len(&self) -> usize {
+    self.max_input_len
+  }
+
+  /// Apply any UTC offset to date-time output and mark it as UTC, e.g. 2023-08-29T19:34:39+05:30
+  /// => 2023-08-29T14:04:39.000Z, rather than preserving the original offset
+  pub fn with_normalize_to_utc(mut self, enabled: bool) -> Self {
+    self.normalize_to_utc = enabled;
+    self
+  }
+
+  pub fn normalize_to_utc(&self) -> bool {
+    self.normalize_to_utc
+  }
+
+  /// Return options with a fixed date order and splitter, keeping all other options
+  pub(crate) fn with_format(self, order: DateOrder, splitter: Option<char>) -> Self {
+    DateOptions {
+      order,
+      splitter,
+      guess: false,
+      ..self
+    }
+  }
+
+  /// Return options that guess the date order and splitter from each date string, keeping all other options
+  pub(crate) fn with_guessing(self) -> Self {
+    DateOptions {
+      guess: true,
+      ..self
+    }
+  }
+
+  /// Return options with the date order and splitter surmised from the date string if required,
+  /// otherwise an identical copy
+  pub(crate) fn resolve(&self, date_str: &str) -> Self {
+    if self.guess {
+      let surmised = surmise_date_order_and_splitter_by_priority(date_str, self.splitter_priority());
+      self.clone().with_format(surmised.order, surmised.splitter)
+    } else {
+      self.clone()
+    }
   }
 }
 
 impl Default for DateOptions {
   fn default() -> Self {
-    DateOptions(DateOrder::YMD, Some('-'))
+    DateOptions::new(DateOrder::YMD, Some('-'))
+  }
+}
+
+/// Build options from a date order and optional splitter, as with the former tuple struct constructor,
+/// e.g. DateOptions::from((DateOrder::DMY, Some('/'))) in place of DateOptions(DateOrder::DMY, Some('/'))
+impl From<(DateOrder, Option<char>)> for DateOptions {
+  fn from((order, splitter): (DateOrder, Option<char>)) -> Self {
+    DateOptions::new(order, splitter)
   }
 }
 
@@ -71,27 +565,38 @@ impl Default for DateOptions {
 /// e.g. DateOptions::dmy('.')
 impl DateOptions {
   pub fn ymd(splitter: char) -> Self {
-    DateOptions(DateOrder::YMD, Some(splitter))
+    DateOptions::new(DateOrder::YMD, Some(splitter))
   }
 
   pub fn ymd_fixed() -> Self {
-    DateOptions(DateOrder::YMD, None)
+    DateOptions::new(DateOrder::YMD, None)
   }
 
   pub fn dmy(splitter: char) -> Self {
-    DateOptions(DateOrder::DMY, Some(splitter))
+    DateOptions::new(DateOrder::DMY, Some(splitter))
   }
 
   pub fn dmy_fixed() -> Self {
-    DateOptions(DateOrder::DMY, None)
+    DateOptions::new(DateOrder::DMY, None)
   }
 
   pub fn mdy(splitter: char) -> Self {
-    DateOptions(DateOrder::MDY, Some(splitter))
+    DateOptions::new(DateOrder::MDY, Some(splitter))
   }
   
   pub fn mdy_fixed() -> Self {
-    DateOptions(DateOrder::MDY, None)
+    DateOptions::new(DateOrder::MDY, None)
   }
 }
 
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_date_options_from_tuple() {
+    let opts = DateOptions::from((DateOrder::DMY, Some('/')));
+    assert_eq!(opts, DateOptions::dmy('/'));
+    assert_eq!(crate::fuzzy_to_date_string("29/08/1993", Some(opts)), Some("1993-08-29".to_string()));
+  }
+}
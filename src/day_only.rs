@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use simple_string_patterns::CharGroupMatch;
+use crate::converters::strip_ordinal_suffix;
+
+/// Extract a bare day from text such as "meeting on the 29th" or "the 1ˢᵗ" and combine it with
+/// a reference year and month known from context. The day must have an ordinal suffix or follow "the",
+/// and be valid for the month, so "the 31st" of a 30-day month returns None
+pub fn parse_day_only(s: &str, year: i32, month: u32) -> Option<NaiveDate> {
+  let tokens: Vec<&str> = s.split_whitespace().map(|token| token.trim_end_matches([',', '.', ';', ':', '!', '?'])).collect();
+  let day = tokens.iter().enumerate().find_map(|(idx, &token)| {
+    let digits = strip_ordinal_suffix(token);
+    let follows_article = idx > 0 && tokens[idx - 1].eq_ignore_ascii_case("the");
+    let is_day = !digits.is_empty() && digits.len() <= 2 && digits.is_digits_only() && (digits != token || follows_article);
+    if is_day {
+      digits.parse::<u32>().ok()
+    } else {
+      None
+    }
+  })?;
+  NaiveDate::from_ymd_opt(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_day_only() {
+    assert_eq!(parse_day_only("the 29th", 2023, 8), NaiveDate::from_ymd_opt(2023, 8, 29));
+    assert_eq!(parse_day_only("meeting on the 3rd, after lunch", 2023, 8), NaiveDate::from_ymd_opt(2023, 8, 3));
+    assert_eq!(parse_day_only("due on the 1ˢᵗ", 2024, 2), NaiveDate::from_ymd_opt(2024, 2, 1));
+    assert_eq!(parse_day_only("see you on the 12", 2023, 8), NaiveDate::from_ymd_opt(2023, 8, 12));
+    // validated against the length of the month
+    assert_eq!(parse_day_only("the 29th", 2023, 2), None);
+    assert_eq!(parse_day_only("the 31st", 2023, 9), None);
+    // a plain number without an ordinal suffix or article is not a day
+    assert_eq!(parse_day_only("room 12", 2023, 8), None);
+  }
+}
@@ -1,7 +1,7 @@
-use crate::{guess::{guess_date_order, guess_date_splitter, DateOrderGuess}, DateOptions, DateOrder};
+use crate::{guess::{guess_date_order, guess_date_splitter, DateOrderGuess}, is_datetime_like, segments::split_datetime_segments, validators::{is_iso_duration, is_time_only}, DateOptions, DateOrder};
 
 /// This assumes all date strings are in the same format
-/// and deduces through elimination
+/// and deduces through elimination, taking the order supported by most strings
 pub fn detect_date_format_from_list(date_list: &[&str]) -> DateOptions {
     detect_date_format_from_generic_list(date_list, |&x| Some(x.to_string()))
   }
@@ -12,32 +12,208 @@ pub fn detect_date_format_from_list(date_list: &[&str]) -> DateOptions {
   where 
       F: Fn(&T) -> Option<String>,
   {
-    let mut order = DateOrder::YMD;
-  
-    for row in date_list {
-      if let Some(dt_str) = extract_date(row) {
-        if dt_str.trim().is_empty() {
-          continue; // Skip empty string
-        }
-        let split_char = guess_date_splitter(&dt_str);
-        let guess = guess_date_order(&dt_str, split_char);
-        match guess {
-            DateOrderGuess::YearFirst => {
-                order = DateOrder::YMD;
-                return DateOptions(order, split_char);
-            },
-            DateOrderGuess::DayFirst => {
-                order = DateOrder::DMY;
-                return DateOptions(order, split_char);
-            },
-            DateOrderGuess::MonthFirst => {
-                order = DateOrder::MDY;
-                return DateOptions(order, split_char);
-            },
-            _ => continue, // NonDate or ambiguous format, keep looking
+    // If we didn't find a conclusive format, we might want to handle this case better
+    detect_conclusive_date_format(date_list, extract_date).unwrap_or_else(|| DateOptions::new(DateOrder::YMD, None))
+  }
+
+  /// As above, but None if no date string settles the date order
+  pub(crate) fn detect_conclusive_date_format<T, F>(date_list: &[T], extract_date: F) -> Option<DateOptions>
+  where 
+      F: Fn(&T) -> Option<String>,
+  {
+    let (tally, splitters) = tally_generic_date_orders(date_list, extract_date);
+    let order = tally.majority_order()?;
+    let splitter = splitters.iter().find(|(seen_order, _)| *seen_order == order).and_then(|(_, splitter)| *splitter);
+    Some(DateOptions::new(order, splitter))
+  }
+
+/// Counts of the date orders that each date string in a list can only be read in,
+/// plus those that could be day-first or month-first, e.g. "05/06/2023"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateOrderTally {
+  pub year_first: usize,
+  pub day_first: usize,
+  pub month_first: usize,
+  pub day_or_month_first: usize,
+}
+
+impl DateOrderTally {
+  /// The order supported by most unambiguous rows. Ties are settled in favour of DMY
+  /// if there are also day-or-month-first rows, and otherwise of YMD, DMY and MDY in that order.
+  /// None if no row settles the order
+  pub fn majority_order(&self) -> Option<DateOrder> {
+    self.ranked_orders().into_iter().next().filter(|&(_, count)| count > 0).map(|(order, _)| order)
+  }
+
+  /// Some rows can only be day-first while others can only be month-first,
+  /// so the list probably mixes formats or contains typos
+  pub fn is_conflicting(&self) -> bool {
+    self.day_first > 0 && self.month_first > 0
+  }
+
+  /// The share of unambiguous rows supporting the majority order from 0.0 to 1.0
+  pub fn confidence(&self) -> f32 {
+    let total = self.year_first + self.day_first + self.month_first;
+    match self.ranked_orders().first() {
+      Some(&(_, count)) if total > 0 => count as f32 / total as f32,
+      _ => 0.0,
+    }
+  }
+
+  /// Orders by descending count, with DMY first among equals if any rows are day-or-month-first
+  fn ranked_orders(&self) -> Vec<(DateOrder, usize)> {
+    let mut ranked = vec![(DateOrder::YMD, self.year_first), (DateOrder::DMY, self.day_first), (DateOrder::MDY, self.month_first)];
+    let favours_dmy = self.day_or_month_first > 0;
+    ranked.sort_by_key(|&(order, count)| (std::cmp::Reverse(count), !(favours_dmy && order == DateOrder::DMY)));
+    ranked
+  }
+}
+
+/// Tally the date orders of all strings in a list, rather than letting the first conclusive string decide,
+/// e.g. to check whether a list mixes day-first and month-first dates before choosing a format
+pub fn tally_date_orders(date_list: &[&str]) -> DateOrderTally {
+  tally_generic_date_orders(date_list, |&x| Some(x.to_string())).0
+}
+
+/// Check whether no date in a list settles the date order, e.g. only "05/06/2023" and "01/02/2023",
+/// so the user may be asked to choose an order before importing
+pub fn is_detection_ambiguous(date_list: &[&str]) -> bool {
+  tally_date_orders(date_list).majority_order().is_none()
+}
+
+/// Tally the date orders as above, plus the splitter of the first string read in each order
+fn tally_generic_date_orders<T, F>(date_list: &[T], extract_date: F) -> (DateOrderTally, Vec<(DateOrder, Option<char>)>)
+where
+    F: Fn(&T) -> Option<String>,
+{
+  let mut tally = DateOrderTally::default();
+  let mut splitters: Vec<(DateOrder, Option<char>)> = vec![];
+  for row in date_list {
+    let Some(dt_str) = extract_date(row) else {
+      continue;
+    };
+    if dt_str.trim().is_empty() || is_iso_duration(&dt_str) || is_time_only(&dt_str) {
+      continue; // Skip empty strings, durations and times without dates
+    }
+    // only the date portion before any time designator, e.g. 'T' or 't', bears on the date order
+    let date_str = split_datetime_segments(&dt_str).date;
+    let split_char = guess_date_splitter(date_str);
+    let order = match guess_date_order(date_str, split_char) {
+      DateOrderGuess::YearFirst => {
+        tally.year_first += 1;
+        DateOrder::YMD
+      },
+      DateOrderGuess::DayFirst => {
+        tally.day_first += 1;
+        DateOrder::DMY
+      },
+      DateOrderGuess::MonthFirst => {
+        tally.month_first += 1;
+        DateOrder::MDY
+      },
+      DateOrderGuess::DayOrMonthFirst => {
+        tally.day_or_month_first += 1;
+        continue;
+      },
+      DateOrderGuess::NonDate => continue,
+    };
+    if splitters.iter().all(|(seen_order, _)| *seen_order != order) {
+      splitters.push((order, split_char));
+    }
+  }
+  (tally, splitters)
+}
+
+/// Detect a shared date format across several date columns, e.g. of a spreadsheet in one locale,
+/// pooling their values so a disambiguating value in any column settles the format for all
+pub fn detect_date_format_from_columns(columns: &[&[&str]]) -> DateOptions {
+  let pooled: Vec<&str> = columns.iter().flat_map(|column| column.iter().copied()).collect();
+  detect_date_format_from_list(&pooled)
+}
+
+/// Detect the date format of each group of dates sharing a splitter, in order of first appearance,
+/// e.g. YMD with '-' and DMY with '/' for a column mixing "2023-08-29" and "29/08/2023".
+/// More than one format flags a mixed column, rather than letting the first conclusive row decide for all.
+/// Groups whose dates never settle the order, e.g. only "05/06/2023", are omitted
+pub fn detect_date_formats_by_splitter(date_list: &[&str]) -> Vec<DateOptions> {
+  let mut groups: Vec<(Option<char>, Vec<&str>)> = vec![];
+  for &row in date_list {
+    let splitter = guess_date_splitter(split_datetime_segments(row).date);
+    match groups.iter_mut().find(|(group_splitter, _)| *group_splitter == splitter) {
+      Some((_, rows)) => rows.push(row),
+      None => groups.push((splitter, vec![row])),
+    }
+  }
+  groups.into_iter()
+    .filter_map(|(_, rows)| detect_conclusive_date_format(&rows, |&row| Some(row.to_string())))
+    .collect()
+}
+
+/// Detect the date format of a named string field in a list of JSON objects
+/// Objects without the field, or where it is not a string, are skipped
+#[cfg(feature = "serde")]
+pub fn detect_date_format_from_json(values: &[serde_json::Value], field: &str) -> DateOptions {
+  detect_date_format_from_generic_list(values, |value| value.get(field).and_then(|v| v.as_str()).map(|v| v.to_string()))
+}
+
+/// Check whether at least the threshold fraction (0.0 to 1.0) of non-empty entries
+/// are date or date-time strings, e.g. to decide which columns of an import are date columns.
+/// Lists with no non-empty entries are never considered date columns.
+pub fn looks_like_date_column(list: &[&str], threshold: f32) -> bool {
+  let mut num_entries = 0;
+  let mut num_dates = 0;
+  for &row in list {
+    if row.trim().is_empty() {
+      continue;
+    }
+    num_entries += 1;
+    if is_datetime_like(row) {
+      num_dates += 1;
+    }
+  }
+  num_entries > 0 && num_dates as f32 / num_entries as f32 >= threshold
+}
+
+/// Parse an explicit format declaration such as "dd/mm/yyyy" or "Date (MM/DD/YYYY)",
+/// e.g. from a CSV column header, into date options
+/// Runs of d, m and y letters (in any case) must each appear once, separated consistently
+/// by '-', '/' or '.' or not at all for fixed-width dates such as "yyyymmdd"
+pub fn parse_format_hint(header: &str) -> Option<DateOptions> {
+  let is_pattern_char = |c: char| matches!(c.to_ascii_lowercase(), 'd' | 'm' | 'y' | '-' | '/' | '.');
+  header
+    .split(|c: char| !is_pattern_char(c))
+    .find_map(format_pattern_to_options)
+}
+
+fn format_pattern_to_options(pattern: &str) -> Option<DateOptions> {
+  let pattern = pattern.trim_matches(|c| matches!(c, '-' | '/' | '.'));
+  let mut letters: Vec<char> = vec![];
+  let mut splitter: Option<char> = None;
+  let mut prev: Option<char> = None;
+  for c in pattern.chars() {
+    let lc = c.to_ascii_lowercase();
+    if lc.is_ascii_alphabetic() {
+      if prev != Some(lc) {
+        if letters.contains(&lc) {
+          return None;
         }
+        letters.push(lc);
+      }
+      prev = Some(lc);
+    } else {
+      // all separators must be the same, with only one between letter groups
+      if prev.is_none() || splitter.is_some_and(|sc| sc != c) {
+        return None;
       }
+      splitter = Some(c);
+      prev = None;
     }
-    // If we didn't find a conclusive format, we might want to handle this case better
-    DateOptions(order, None)
-  }
\ No newline at end of file
+  }
+  let order = match letters.as_slice() {
+    ['y', 'm', 'd'] => DateOrder::YMD,
+    ['d', 'm', 'y'] => DateOrder::DMY,
+    ['m', 'd', 'y'] => DateOrder::MDY,
+    _ => return None,
+  };
+  Some(DateOptions::new(order, splitter))
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+/// Why a string could not be read as a date or date-time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FuzzyDateError {
+  /// The input is empty or only whitespace
+  EmptyInput,
+  /// The input is longer than the maximum length set in DateOptions
+  TooLong(usize),
+  /// The input contains text that is neither a number nor a recognised date word, e.g. "apple"
+  NonDateText(String),
+  /// No year with at least 4 digits, or 2 digits in a full day-month-year date, was found
+  NoYear,
+  /// The year has fewer than 4 digits, e.g. 203
+  YearTooSmall(u16),
+  MonthOutOfRange(u16),
+  /// The day exceeds 31 or the number of days in its month
+  DayOutOfRange(u16),
+  HourOutOfRange(u8),
+  MinuteOutOfRange(u8),
+  SecondOutOfRange(u8),
+  /// The time is neither separated nor a compact HH, HHMM or HHMMSS time
+  InvalidTime(String),
+  /// The date falls outside the valid range set in DateOptions
+  OutsideValidRange,
+  /// The date is 9999-12-31, rejected as a sentinel for "never" by DateOptions
+  SentinelDate,
+  /// The input does not match any supported date format
+  Unrecognised,
+}
+
+impl fmt::Display for FuzzyDateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FuzzyDateError::EmptyInput => write!(f, "the input is empty"),
+      FuzzyDateError::TooLong(max_len) => write!(f, "the input is longer than {} bytes", max_len),
+      FuzzyDateError::NonDateText(text) => write!(f, "contains unexpected text '{}'", text),
+      FuzzyDateError::NoYear => write!(f, "no 4-digit year found"),
+      FuzzyDateError::YearTooSmall(year) => write!(f, "year {} has fewer than 4 digits", year),
+      FuzzyDateError::MonthOutOfRange(month) => write!(f, "month {} is out of range", month),
+      FuzzyDateError::DayOutOfRange(day) => write!(f, "day {} is out of range", day),
+      FuzzyDateError::HourOutOfRange(hour) => write!(f, "hour {} is out of range", hour),
+      FuzzyDateError::MinuteOutOfRange(minute) => write!(f, "minute {} is out of range", minute),
+      FuzzyDateError::SecondOutOfRange(second) => write!(f, "second {} is out of range", second),
+      FuzzyDateError::InvalidTime(time) => write!(f, "time '{}' is not recognised", time),
+      FuzzyDateError::OutsideValidRange => write!(f, "the date is outside the valid range"),
+      FuzzyDateError::SentinelDate => write!(f, "9999-12-31 is a sentinel rather than a real date"),
+      FuzzyDateError::Unrecognised => write!(f, "not recognised as a date"),
+    }
+  }
+}
+
+impl std::error::Error for FuzzyDateError {}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use crate::{
+  clock::{is_meridiem_marker, move_leading_meridiem, split_leading_hour, split_meridiem},
+  converters::{alpha_month_to_index, alpha_weekday_to_index, is_date_unit_marker, is_day_article, strip_ordinal_suffix, time_keyword_to_time, try_fuzzy_to_formatted_time_parts, try_to_formatted_date_string_anchored},
+  fuzzy_to_datetime, is_sentinel_date, split_datetime_segments, DateOptions, FuzzyDateError,
+};
+
+/// Explain in plain words why a string cannot be parsed as a date or date-time with fuzzy_to_datetime,
+/// e.g. "month 13 is out of range" for "2023-13-01" or "contains unexpected text 'apple'".
+/// Returns None if the string parses
+pub fn explain_failure(s: &str, date_opts: Option<DateOptions>) -> Option<String> {
+  diagnose_failure(s, date_opts).map(|error| error.to_string())
+}
+
+/// Find the first reason a string fails to parse, checking for stray words, then the date and then the time
+pub(crate) fn diagnose_failure(s: &str, date_opts: Option<DateOptions>) -> Option<FuzzyDateError> {
+  if fuzzy_to_datetime(s, date_opts.clone(), None).is_ok() {
+    return None;
+  }
+  let trimmed = s.trim();
+  if trimmed.is_empty() {
+    return Some(FuzzyDateError::EmptyInput);
+  }
+  let max_len = date_opts.as_ref().map_or_else(|| DateOptions::auto().max_input_len(), DateOptions::max_input_len);
+  if s.len() > max_len {
+    return Some(FuzzyDateError::TooLong(max_len));
+  }
+  if let Some(word) = first_unexpected_word(trimmed) {
+    return Some(FuzzyDateError::NonDateText(word.to_string()));
+  }
+  let moved = move_leading_meridiem(trimmed);
+  let text = moved.as_deref().unwrap_or(trimmed);
+  let (base, meridiem) = split_meridiem(text).map_or((text, None), |(base, meridiem)| (base, Some(meridiem)));
+  let segments = split_datetime_segments(base);
+  // dates with month names are matched as a whole, so only numeric dates can be checked by component
+  if !segments.date.chars().any(char::is_alphabetic) {
+    let opts = date_opts.unwrap_or_else(DateOptions::auto).resolve(segments.date);
+    let formatted_date = match try_to_formatted_date_string_anchored(segments.date, opts.order(), opts.splitter(), opts.period_anchor(), opts.two_digit_years()) {
+      Ok(formatted_date) => formatted_date,
+      Err(error) => return Some(error),
+    };
+    match NaiveDate::parse_from_str(&formatted_date, "%Y-%m-%d") {
+      // the day is within 31 but not within its month, e.g. 30 February
+      Err(_) => return Some(FuzzyDateError::DayOutOfRange(formatted_date[8..].parse().unwrap_or(0))),
+      Ok(date) if opts.reject_sentinels() && is_sentinel_date(&date) => return Some(FuzzyDateError::SentinelDate),
+      Ok(date) if !opts.is_date_in_valid_range(&date) => return Some(FuzzyDateError::OutsideValidRange),
+      Ok(_) => (),
+    }
+  }
+  if let Some(time) = segments.time.filter(|&time| time_keyword_to_time(time).is_none()) {
+    // with AM/PM, the hour must be on the 12-hour clock
+    if let Some(hour) = meridiem.and_then(|_| split_leading_hour(time).0.parse::<u8>().ok()).filter(|hour| !(1..=12).contains(hour)) {
+      return Some(FuzzyDateError::HourOutOfRange(hour));
+    }
+    if let Err(error) = try_fuzzy_to_formatted_time_parts(time, segments.fraction.unwrap_or_default(), None, false, '.', 3) {
+      return Some(error);
+    }
+  }
+  Some(FuzzyDateError::Unrecognised)
+}
+
+/// The first word of 2 or more letters that is not a month, weekday, ordinal suffix, AM/PM marker, time keyword, day article or date unit
+pub(crate) fn first_unexpected_word(text: &str) -> Option<&str> {
+  text.split(|c: char| !c.is_alphabetic())
+    .find(|word| word.chars().count() > 1 && !is_date_word(word))
+}
+
+fn is_date_word(word: &str) -> bool {
+  alpha_month_to_index(word).is_some()
+    || alpha_weekday_to_index(word).is_some()
+    || time_keyword_to_time(word).is_some()
+    || strip_ordinal_suffix(&format!("1{}", word)) == "1"
+    || is_meridiem_marker(word)
+    || is_day_article(word)
+    || is_date_unit_marker(word)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_explain_failure() {
+    assert_eq!(explain_failure("2023-08-29 19:34:39", None), None);
+    assert_eq!(explain_failure("29 August 2023, 7:34 pm", None), None);
+    assert_eq!(explain_failure("2023-08-29 下午 13:15", None).as_deref(), Some("hour 13 is out of range"));
+    assert_eq!(explain_failure("2023-08-29 0:30 am", None).as_deref(), Some("hour 0 is out of range"));
+    let explanations = [
+      ("  ", "the input is empty"),
+      ("apple 2023-08-29", "contains unexpected text 'apple'"),
+      ("12/05", "no 4-digit year found"),
+      ("203-08-29", "year 203 has fewer than 4 digits"),
+      ("2023-13-01", "month 13 is out of range"),
+      ("2023-08-32", "day 32 is out of range"),
+      ("2023-02-30", "day 30 is out of range"),
+      ("2023-08-29 25:00", "hour 25 is out of range"),
+      ("29.08.2023 19:61", "minute 61 is out of range"),
+      ("2023-08-29T19:34:75", "second 75 is out of range"),
+      ("2023-08-29 19345", "time '19345' is not recognised"),
+    ];
+    for (input, explanation) in explanations {
+      assert_eq!(explain_failure(input, None).as_deref(), Some(explanation), "explaining {:?}", input);
+    }
+    let opts = DateOptions::default().with_valid_range(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2030, 12, 31).unwrap());
+    assert_eq!(explain_failure("1899-08-29", Some(opts)).as_deref(), Some("the date is outside the valid range"));
+  }
+}
@@ -0,0 +1,34 @@
+use std::ops::Range;
+use chrono::NaiveDate;
+use crate::{fuzzy_to_date, DateOptions};
+
+/// Parse a date field at a byte range of a fixed-width record, e.g. columns 10 to 17 of a COBOL-style file.
+/// The range end is capped at the end of the line, as trailing spaces are often stripped,
+/// and the field is trimmed. Returns None if the range falls outside the line or within a multibyte character
+pub fn parse_fixed_field(line: &str, range: Range<usize>, date_opts: Option<DateOptions>) -> Option<NaiveDate> {
+  let field = line.get(range.start..range.end.min(line.len()))?.trim();
+  if field.is_empty() {
+    return None;
+  }
+  fuzzy_to_date(field, date_opts).ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::DateOrder;
+
+  #[test]
+  fn test_parse_fixed_field() {
+    let line = "ACME0001 20230829 000150";
+    let compact = Some(DateOptions::new(DateOrder::YMD, None));
+    assert_eq!(parse_fixed_field(line, 9..17, compact.clone()), NaiveDate::from_ymd_opt(2023, 8, 29));
+    assert_eq!(parse_fixed_field("Zoë 29/08/2023", 5..16, None), NaiveDate::from_ymd_opt(2023, 8, 29));
+    // the end may extend beyond a line with stripped trailing spaces
+    assert_eq!(parse_fixed_field("ACME0001 20230829", 9..20, compact.clone()), NaiveDate::from_ymd_opt(2023, 8, 29));
+    // not a character boundary, beyond the line or blank
+    assert_eq!(parse_fixed_field("Zoë 29/08/2023", 3..14, None), None);
+    assert_eq!(parse_fixed_field(line, 30..38, compact.clone()), None);
+    assert_eq!(parse_fixed_field("ACME0001         000150", 9..17, compact), None);
+  }
+}
@@ -0,0 +1,148 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use crate::{converters::{MONTH_NAMES, WEEKDAY_NAMES}, fuzzy_to_date_and_time, DateOptions, DateOrder};
+
+/// Template tokens, with longer tokens before their prefixes so they match first
+const PATTERN_TOKENS: [&str; 13] = ["YYYY", "YY", "MMMM", "MMM", "MM", "M", "DD", "D", "hh", "h", "mm", "ss", "SSS"];
+
+/// Render a date with zero-padded components in the given order and with the given splitter,
+/// e.g. 29/08/2023 in DMY order with '/'
+pub fn format_date(date: NaiveDate, order: DateOrder, splitter: char) -> String {
+  let year = format!("{:04}", date.year());
+  let month = format!("{:02}", date.month());
+  let day = format!("{:02}", date.day());
+  let parts = match order {
+    DateOrder::YMD => [year, month, day],
+    DateOrder::DMY => [day, month, year],
+    DateOrder::MDY => [month, day, year],
+  };
+  parts.join(&splitter.to_string())
+}
+
+/// Render a date-time with the date as above followed by a space and the 24-hour time, e.g. 29/08/2023 19:34:39
+pub fn format_datetime(datetime: NaiveDateTime, order: DateOrder, splitter: char) -> String {
+  format!("{} {}", format_date(datetime.date(), order, splitter), datetime.format("%H:%M:%S"))
+}
+
+/// Render a date prefixed with the full English weekday name, e.g. "Tuesday, 2023-08-29"
+pub fn format_with_weekday(date: NaiveDate, order: DateOrder, splitter: char) -> String {
+  let weekday_name = WEEKDAY_NAMES[date.weekday().num_days_from_monday() as usize];
+  format!("{}, {}", capitalize(weekday_name), format_date(date, order, splitter))
+}
+
+/// Render the hours and minutes of a time on the 12-hour clock with an AM/PM marker, e.g. "7:15 PM".
+/// Midnight is 12:00 AM and noon is 12:00 PM
+pub fn format_time_12h(time: NaiveTime) -> String {
+  let (is_pm, hour) = time.hour12();
+  format!("{}:{:02} {}", hour, time.minute(), if is_pm { "PM" } else { "AM" })
+}
+
+/// Render a date-time with a simple template rather than chrono's % codes, e.g. "YYYY/MM/DD hh:mm" => "2023/08/29 19:34".
+/// The tokens are YYYY and YY for the year, MMMM, MMM, MM and M for the month name, its abbreviation and number,
+/// DD and D for the day, hh and h for the 24-hour hour, mm for minutes, ss for seconds and SSS for milliseconds.
+/// Two-letter tokens are zero-padded and any other text is copied as is. Tokens match anywhere, even within words,
+/// so words are quoted to keep them literal, e.g. "'Date:' DD" => "Date: 09", with '' for a single quote
+pub fn format_with_pattern(dt: NaiveDateTime, pattern: &str) -> String {
+  let month_name = capitalize(MONTH_NAMES[dt.month0() as usize]);
+  let mut output = String::with_capacity(pattern.len() + 8);
+  let mut rest = pattern;
+  while let Some(c) = rest.chars().next() {
+    if c == '\'' {
+      let quoted = &rest[1..];
+      let end = quoted.find('\'').unwrap_or(quoted.len());
+      output.push_str(if end == 0 { "'" } else { &quoted[..end] });
+      rest = quoted.get(end + 1..).unwrap_or("");
+      continue;
+    }
+    let Some(token) = PATTERN_TOKENS.iter().find(|token| rest.starts_with(**token)) else {
+      output.push(c);
+      rest = &rest[c.len_utf8()..];
+      continue;
+    };
+    let value = match *token {
+      "YYYY" => format!("{:04}", dt.year()),
+      "YY" => format!("{:02}", dt.year().rem_euclid(100)),
+      "MMMM" => month_name.clone(),
+      "MMM" => month_name.chars().take(3).collect(),
+      "MM" => format!("{:02}", dt.month()),
+      "M" => dt.month().to_string(),
+      "DD" => format!("{:02}", dt.day()),
+      "D" => dt.day().to_string(),
+      "hh" => format!("{:02}", dt.hour()),
+      "h" => dt.hour().to_string(),
+      "mm" => format!("{:02}", dt.minute()),
+      "ss" => format!("{:02}", dt.second()),
+      _ => format!("{:03}", dt.nanosecond() / 1_000_000),
+    };
+    output.push_str(&value);
+    rest = &rest[token.len()..];
+  }
+  output
+}
+
+fn capitalize(name: &str) -> String {
+  let mut chars = name.chars();
+  chars.next().map(|c| c.to_ascii_uppercase()).into_iter().chain(chars).collect()
+}
+
+/// Parse a date or date-time in the source format and render it in the target order and splitter,
+/// e.g. "08/29/2023" from MDY with '/' => "2023-08-29" in YMD with '-'.
+/// Any time is kept as in format_datetime. A target without a splitter uses '-'
+pub fn reformat(s: &str, from: DateOptions, to: DateOptions) -> Option<String> {
+  let (date, time) = fuzzy_to_date_and_time(s, Some(from))?;
+  let splitter = to.splitter().unwrap_or('-');
+  Some(match time {
+    Some(time) => format_datetime(date.and_time(time), to.order(), splitter),
+    None => format_date(date, to.order(), splitter),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_with_weekday() {
+    let date = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap();
+    assert_eq!(format_date(date, DateOrder::DMY, '/'), "29/08/2023");
+    assert_eq!(format_date(date, DateOrder::MDY, '.'), "08.29.2023");
+    assert_eq!(format_datetime(date.and_hms_opt(19, 34, 39).unwrap(), DateOrder::DMY, '/'), "29/08/2023 19:34:39");
+    assert_eq!(format_with_weekday(date, DateOrder::YMD, '-'), "Tuesday, 2023-08-29");
+    let sunday = NaiveDate::from_ymd_opt(2023, 9, 3).unwrap();
+    assert_eq!(format_with_weekday(sunday, DateOrder::DMY, '.'), "Sunday, 03.09.2023");
+  }
+
+  #[test]
+  fn test_format_time_12h() {
+    let time = |hour: u32, minute: u32| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+    assert_eq!(format_time_12h(time(19, 15)), "7:15 PM");
+    assert_eq!(format_time_12h(time(7, 5)), "7:05 AM");
+    assert_eq!(format_time_12h(time(0, 0)), "12:00 AM");
+    assert_eq!(format_time_12h(time(12, 0)), "12:00 PM");
+    assert_eq!(format_time_12h(time(23, 59)), "11:59 PM");
+    // the output can be parsed back
+    assert_eq!(crate::fuzzy_to_time(&format_time_12h(time(19, 15))), Some(time(19, 15)));
+  }
+
+  #[test]
+  fn test_format_with_pattern() {
+    let dt = NaiveDate::from_ymd_opt(2023, 8, 9).unwrap().and_hms_milli_opt(19, 4, 39, 78).unwrap();
+    assert_eq!(format_with_pattern(dt, "YYYY/MM/DD hh:mm"), "2023/08/09 19:04");
+    assert_eq!(format_with_pattern(dt, "D MMMM YYYY"), "9 August 2023");
+    assert_eq!(format_with_pattern(dt, "MMM D, YY"), "Aug 9, 23");
+    assert_eq!(format_with_pattern(dt, "DD.MM.YYYY h:mm:ss.SSS"), "09.08.2023 19:04:39.078");
+    assert_eq!(format_with_pattern(dt, "M/D at hh"), "8/9 at 19");
+    // quoted text is literal
+    assert_eq!(format_with_pattern(dt, "'Date:' DD"), "Date: 09");
+    assert_eq!(format_with_pattern(dt, "'Made on' D MMMM 'at' hh'h'mm"), "Made on 9 August at 19h04");
+    assert_eq!(format_with_pattern(dt, "D MMM ''YY"), "9 Aug '23");
+    assert_eq!(format_with_pattern(dt, "YYYY 'unclosed DD"), "2023 unclosed DD");
+  }
+
+  #[test]
+  fn test_reformat() {
+    let mdy = DateOptions::mdy('/');
+    assert_eq!(reformat("08/29/2023", mdy.clone(), DateOptions::default()).as_deref(), Some("2023-08-29"));
+    assert_eq!(reformat("08/29/2023 19:34:39", mdy.clone(), DateOptions::dmy('.')).as_deref(), Some("29.08.2023 19:34:39"));
+    assert_eq!(reformat("29/08/2023", mdy, DateOptions::default()), None);
+  }
+}
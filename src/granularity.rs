@@ -0,0 +1,79 @@
+use simple_string_patterns::{CharGroupMatch, CharType, SimplContainsType};
+use crate::{converters::strip_ordinal_suffix, period::quarter_or_half_to_months, segments::split_datetime_segments, DateOptions};
+
+/// The finest unit of time explicitly present in a source date string,
+/// ordered from the coarsest to the finest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Granularity {
+  Decade,
+  Year,
+  Month,
+  Day,
+  Hour,
+  Minute,
+  Second,
+  Subsecond,
+}
+
+/// the granularity of a date without a time from the number of its components,
+/// e.g. Month for "2023-08", "202308", "Aug 2023" or "2023-Q3"
+pub(crate) fn date_granularity(date_str: &str, date_opts: &DateOptions) -> Granularity {
+  if quarter_or_half_to_months(date_str).is_some() {
+    return Granularity::Month;
+  }
+  let num_parts = if date_str.contains_type(CharType::Alpha) {
+    // numbers alongside a named month
+    date_str.split_whitespace().take(3).filter(|token| strip_ordinal_suffix(token.trim_end_matches([',', '.'])).is_digits_only()).count() + 1
+  } else {
+    let date_part = split_datetime_segments(date_str).date;
+    match date_opts.resolve(date_part).splitter() {
+      Some(splitter) if date_part.contains(splitter) => date_part.split(splitter).filter(|part| !part.is_empty()).count(),
+      _ => date_part.len().div_ceil(2).saturating_sub(1),
+    }
+  };
+  match num_parts {
+    0 | 1 => Granularity::Year,
+    2 => Granularity::Month,
+    _ => Granularity::Day,
+  }
+}
+
+/// the granularity of a time from the number of its components, e.g. Minute for "19:34" or "1934",
+/// or Subsecond if it has a fraction of a second
+pub(crate) fn time_granularity(time: &str, has_fraction: bool) -> Granularity {
+  if has_fraction {
+    return Granularity::Subsecond;
+  }
+  let num_separators = time.chars().filter(|c| matches!(c, ':' | '.')).count();
+  let num_parts = if num_separators > 0 {
+    num_separators + 1
+  } else {
+    time.chars().filter(char::is_ascii_digit).count().div_ceil(2)
+  };
+  match num_parts {
+    0 | 1 => Granularity::Hour,
+    2 => Granularity::Minute,
+    _ => Granularity::Second,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_date_and_time_granularity() {
+    let opts = DateOptions::auto();
+    assert_eq!(date_granularity("2023", &opts), Granularity::Year);
+    assert_eq!(date_granularity("2023-08", &opts), Granularity::Month);
+    assert_eq!(date_granularity("202308", &opts), Granularity::Month);
+    assert_eq!(date_granularity("Aug 2023", &opts), Granularity::Month);
+    assert_eq!(date_granularity("2023-Q3", &opts), Granularity::Month);
+    assert_eq!(date_granularity("29th Aug 2023", &opts), Granularity::Day);
+    assert_eq!(date_granularity("20230829", &opts), Granularity::Day);
+    assert_eq!(time_granularity("19", false), Granularity::Hour);
+    assert_eq!(time_granularity("1934", false), Granularity::Minute);
+    assert_eq!(time_granularity("19:34:39", false), Granularity::Second);
+    assert_eq!(time_granularity("19:34:39", true), Granularity::Subsecond);
+  }
+}
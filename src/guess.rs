@@ -1,5 +1,5 @@
 use simple_string_patterns::{CharGroupMatch, StripCharacters};
-use crate::{converters::digits_to_date_parts, date_order::{DateOptions, DateOrder}};
+use crate::{converters::digits_to_date_parts, date_order::{DateOptions, DateOrder}, segments::split_datetime_segments};
 use to_segments::ToSegments;
 
 /// Probable date-time format when comparing many sample date strings
@@ -27,8 +27,15 @@ impl DateOrderGuess {
 
 /// Detect the date order and splitter from a date string
 pub fn surmise_date_order_and_splitter(date_str: &str) -> DateOptions {
-    let splitter = guess_date_splitter(date_str);
-    DateOptions(surmise_date_order(date_str, splitter), splitter)
+    surmise_date_order_and_splitter_by_priority(date_str, None)
+  }
+
+  /// As above, but if a splitter priority is given, the first separator in that list
+  /// found in the date wins rather than the first separator by position
+  pub(crate) fn surmise_date_order_and_splitter_by_priority(date_str: &str, priority: Option<&[char]>) -> DateOptions {
+    let date_str = split_datetime_segments(date_str).date;
+    let splitter = guess_date_splitter_by_priority(date_str, priority);
+    DateOptions::new(surmise_date_order(date_str, splitter), splitter)
   }
   
   pub fn surmise_date_order(date_str: &str, splitter: Option<char>) -> DateOrder {
@@ -81,11 +88,20 @@ pub fn surmise_date_order_and_splitter(date_str: &str) -> DateOptions {
       0
     };
   
+    // a month and year with the 4-digit year last, e.g. 08-2023
+    if num_parts == 2 && first_len < 3 && date_parts[1].len() == 4 {
+      return DateOrderGuess::MonthFirst;
+    }
     // It's not a date, if the first element's length is less than 4 and there are fewer than 3 parts 
     // or otherwise if the first element has no digits
     if (first_len < 1 && num_parts > 2) || (first_len < 4 && num_parts < 3) {
       return DateOrderGuess::NonDate;
     }
+    // identifiers and phone numbers such as 555-1234 or 12-3456-7890 have parts too long
+    // or too large for their positions in any date
+    if !is_plausible_date_parts(&date_parts) {
+      return DateOrderGuess::NonDate;
+    }
     // If the length of the first segment is 4, it's likely a year
     if num_parts < 2 || first_len == 4 {
       DateOrderGuess::YearFirst
@@ -117,18 +133,26 @@ pub fn surmise_date_order_and_splitter(date_str: &str) -> DateOptions {
     }
   }
 
+  /// Guess the date splitter from the date portion only, so separators in any time
+  /// component, e.g. the dots in "20230829 19.34.39", are never considered
   pub(crate) fn guess_date_splitter(date_str: &str) -> Option<char> {
-    if let Some(splitter) = guess_unit_splitter(date_str, &['-', '/', '.']) {
+    guess_date_splitter_by_priority(date_str, None)
+  }
+
+  pub(crate) fn guess_date_splitter_by_priority(date_str: &str, priority: Option<&[char]>) -> Option<char> {
+    let date_str = split_datetime_segments(date_str).date;
+    let matched_splitter = if let Some(separators) = priority {
+      separators.iter().copied().find(|&sep| guess_unit_splitter(date_str, &[sep]).is_some())
+    } else {
+      guess_unit_splitter(date_str, &['-', '/', '.'])
+    };
+    if let Some(splitter) = matched_splitter {
       Some(splitter)
     } else {
-      if date_str.contains("T") {
-        Some('T')
+      if date_str.strip_non_digits().len() >= 8 {
+        None
       } else {
-        if date_str.strip_non_digits().len() >= 8 {
-          None
-        } else {
-          Some(':')
-        }
+        Some(':')
       }
     }
   }
@@ -158,6 +182,22 @@ pub fn surmise_date_order_and_splitter(date_str: &str) -> DateOptions {
   }
 
 
+  /// Years have 4 digits (or 2 if abbreviated) and come first or last,
+  /// while the month and day in the other positions may not exceed 12 and 31
+  fn is_plausible_date_parts(date_parts: &[String]) -> bool {
+    let lens: Vec<usize> = date_parts.iter().map(|part| part.len()).collect();
+    if lens.iter().any(|&len| len == 3 || len > 4) {
+      return false;
+    }
+    let nums: Vec<u16> = date_parts.iter().map(|part| str_to_u16(part)).collect();
+    match (lens.as_slice(), nums.as_slice()) {
+      ([4, _, _], [_, month, day]) => *month <= 12 && *day <= 31,
+      ([_, _, 4], [first, second, _]) => *first <= 31 && *second <= 31 && (*first <= 12 || *second <= 12),
+      ([_, 4, _], _) => false,
+      _ => true,
+    }
+  }
+
   fn str_to_u16(s: &str) -> u16 {
     s.parse::<u16>().unwrap_or(0)
   }
\ No newline at end of file
@@ -1,6 +1,6 @@
-use chrono::{NaiveDate, NaiveDateTime, ParseError};
+use std::borrow::Cow;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, ParseError, TimeDelta, Timelike, Weekday};
 use simple_string_patterns::{CharGroupMatch, CharType, SimplContainsType};
-use to_segments::ToSegments;
 
 mod date_order;
 mod guess;
@@ -8,19 +8,104 @@ mod validators;
 mod converters;
 mod detect;
 mod from_fuzzy_iso_string;
+mod to_fuzzy_string;
+mod calendar;
+mod granularity;
+mod period;
+mod year_month;
+mod month_day;
+mod offset;
+mod clock;
+mod number_locale;
+mod url_path;
+mod candidates;
+mod segments;
+mod range;
+mod format;
+mod day_only;
+mod error;
+mod explain;
+mod partial;
+mod sentinel;
+mod csv_column;
+mod repair;
+mod chain;
+mod fixed_field;
+mod serial;
+mod prefix;
+mod best_effort;
+mod parsed;
+mod bucket;
+#[cfg(feature = "locales")]
+mod locale;
 
-pub use date_order::{DateOrder, DateOptions};
-pub use detect::{detect_date_format_from_list, detect_date_format_from_generic_list};
+pub use date_order::{DateOrder, DateOptions, TwoPartDates};
+pub use detect::{detect_date_format_from_list, detect_date_format_from_columns, detect_date_format_from_generic_list, detect_date_formats_by_splitter, is_detection_ambiguous, looks_like_date_column, parse_format_hint, tally_date_orders, DateOrderTally};
+#[cfg(feature = "serde")]
+pub use detect::detect_date_format_from_json;
 pub use from_fuzzy_iso_string::*;
-use guess::surmise_date_order_and_splitter;
-use validators::segment_is_subseconds;
-use converters::{fuzzy_to_formatted_time_parts, to_formatted_date_string};
+pub use to_fuzzy_string::ToFuzzyString;
+pub use guess::surmise_date_order_and_splitter;
+pub use calendar::{fuzzy_calendar_to_date, CalendarConverter};
+pub use clock::Clock;
+pub use number_locale::NumberLocale;
+pub use url_path::extract_date_from_path;
+pub use candidates::parse_candidates;
+pub use segments::{split_datetime_segments, DateTimeSegments};
+pub use range::fuzzy_to_date_range;
+pub use format::{format_date, format_datetime, format_time_12h, format_with_pattern, format_with_weekday, reformat};
+pub use day_only::parse_day_only;
+pub use error::FuzzyDateError;
+pub use explain::explain_failure;
+pub use partial::is_valid_partial;
+pub use sentinel::is_sentinel_date;
+pub use csv_column::parse_csv_date_column;
+pub use repair::repair_datetime;
+pub use chain::{ParseStage, ParserChain};
+pub use fixed_field::parse_fixed_field;
+pub use serial::{epoch_millis_to_datetime, epoch_to_datetime, epoch_to_datetime_flagged, excel_serial_to_datetime, parse_epoch, EpochWarning};
+pub use prefix::parse_datetime_len;
+pub use best_effort::parse_best_effort;
+pub use parsed::ParsedDateTime;
+pub use bucket::bucket_by;
+#[cfg(feature = "locales")]
+pub use locale::Locale;
+pub use granularity::Granularity;
+pub use period::{Period, PeriodAnchor};
+pub use year_month::{fuzzy_to_year_month, YearMonth};
+pub use month_day::{fuzzy_to_month_day, MonthDay};
+use clock::{move_leading_meridiem, split_meridiem, to_24_hour};
+use validators::{has_excess_leading_zeros, is_complete_date_with_splitter, observed_splitters};
+use converters::{alpha_weekday_to_index, canonical_iso_to_date, canonical_iso_to_datetime, days_in_month, decade_to_year, dotted_ordinal_date_to_iso, format_subseconds, fraction_of_day_to_time, fuzzy_to_formatted_time_parts, join_digit_groups, named_month_date_to_iso, split_leading_weekday, space_joined_month_name_date, split_parenthetical_suffix, split_trailing_adjustment, starts_with_unit_marked_number, strip_day_article, strip_filler_words, time_keyword_to_time, to_formatted_date_string_anchored, two_part_date_to_iso, unit_marked_date_to_iso, weekday_alias_to_index};
+use period::quarter_or_half_to_months;
+use segments::{split_datetime_segments_by, split_time_segments};
+use explain::diagnose_failure;
+use offset::{format_utc_offset, utc_offset_minutes};
 
 /// If the second argument is None, the function will attempt to guess the date order
 /// Otherwise, it will use the provided date order and splitter
 pub fn fuzzy_to_datetime(dt: &str, date_opts: Option<DateOptions>, time_separator: Option<char>) -> Result<NaiveDateTime, ParseError> {
+  // fast path for canonical ISO input that needs no normalisation
+  if date_opts.as_ref().map_or_else(|| DateOptions::auto().accepts_canonical_iso(dt), |opts| opts.accepts_canonical_iso(dt)) && time_separator.is_none_or(|sep| sep == ':') {
+    if let Some(datetime) = canonical_iso_to_datetime(dt) {
+      if date_opts.as_ref().is_none_or(|opts| opts.is_date_in_valid_range(&datetime.date())) {
+        return Ok(datetime);
+      }
+    }
+  }
+  // chrono expects a full date-time with the standard subsecond separator whatever the output preferences
+  let date_opts = date_opts.map(|opts| opts.with_subsecond_separator('.').with_preserve_date_only(false));
   let formatted_str = fuzzy_to_datetime_string(dt, date_opts, time_separator).unwrap_or_default();
-  NaiveDateTime::parse_from_str(&formatted_str, "%Y-%m-%dT%H:%M:%S%.3fZ")
+  // apply any UTC offset preserved in the formatted string
+  DateTime::parse_from_rfc3339(&formatted_str).map(|datetime| datetime.naive_utc())
+}
+
+/// As fuzzy_to_datetime, but preserving up to 9 subsecond digits, e.g. 19:34:39.123456789,
+/// rather than truncating them to milliseconds
+pub fn fuzzy_to_datetime_nanos(dt: &str, date_opts: Option<DateOptions>) -> Option<NaiveDateTime> {
+  let parsed = ParsedDateTime::parse(dt, date_opts.clone())?;
+  // decades bypass the date parser, so check the range and sentinels here too
+  date_opts.is_none_or(|opts| opts.is_date_in_valid_range(&parsed.date)).then(|| parsed.to_naive_datetime())
 }
 
 /// convert a date-time-like string to a valid ISO 8601-compatible date-time string
@@ -38,12 +123,73 @@ pub fn fuzzy_to_date(dt: &str, date_opts: Option<DateOptions>) -> Result<NaiveDa
   NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
 }
 
+/// As fuzzy_to_date, but returning the default date if the string cannot be parsed
+pub fn fuzzy_to_date_or(dt: &str, default: NaiveDate, date_opts: Option<DateOptions>) -> NaiveDate {
+  fuzzy_to_date(dt, date_opts).unwrap_or(default)
+}
+
+/// As fuzzy_to_datetime, but returning the default date-time if the string cannot be parsed
+pub fn fuzzy_to_datetime_or(dt: &str, default: NaiveDateTime, date_opts: Option<DateOptions>) -> NaiveDateTime {
+  fuzzy_to_datetime(dt, date_opts, None).unwrap_or(default)
+}
+
+/// Parse a date only if it exactly matches one of the allowed formats, trying each in turn,
+/// e.g. to accept YMD with hyphens or DMY with slashes but nothing else. A match needs the
+/// splitter and all 3 components, or 8 digits for a format without a splitter, and no guessing
+/// applies beyond options created with DateOptions::auto()
+pub fn parse_with_allowed_formats(s: &str, allowed: &[DateOptions]) -> Option<NaiveDate> {
+  let date_part = split_datetime_segments(s).date;
+  allowed.iter().find_map(|date_opts| {
+    let format = date_opts.resolve(date_part);
+    if !is_complete_date_with_splitter(date_part, format.splitter()) {
+      return None;
+    }
+    fuzzy_to_date(s, Some(format)).ok()
+  })
+}
+
+/// Parse a date as both day-first and month-first with the given splitter, returning it only if exactly one order
+/// yields a valid date or both yield the same date, e.g. 2023-08-15 for "15/08/2023" but None for "08/07/2023",
+/// so ambiguous dates are never guessed
+pub fn parse_if_unambiguous(s: &str, splitter: char) -> Option<NaiveDate> {
+  let dmy = fuzzy_to_date(s, Some(DateOptions::dmy(splitter))).ok();
+  let mdy = fuzzy_to_date(s, Some(DateOptions::mdy(splitter))).ok();
+  match (dmy, mdy) {
+    (Some(date), None) | (None, Some(date)) => Some(date),
+    (Some(dmy_date), Some(mdy_date)) if dmy_date == mdy_date => Some(dmy_date),
+    _ => None,
+  }
+}
+
+/// Parse a date as with fuzzy_to_date, but only return it if it falls on the expected weekday,
+/// e.g. to check payroll dates are always Fridays
+pub fn parse_expect_weekday(s: &str, expected: Weekday, date_opts: Option<DateOptions>) -> Option<NaiveDate> {
+  fuzzy_to_date(s, date_opts).ok().filter(|date| date.weekday() == expected)
+}
+
 /// Convert a ISO YMD date-like string to a NaiveDate
 /// It assumes Y-M-D order and a hyphen as the splitter, but can accommodate missing month or day components
 pub fn iso_fuzzy_to_date(dt: &str) -> Result<NaiveDate, ParseError> {
   fuzzy_to_date(dt, Some(DateOptions::default()))
 }
 
+/// Recognise decade notation such as "2020s" or "1990's" in casual text
+/// returning the first day of the decade flagged with Granularity::Decade, as the exact date is unknown
+pub fn fuzzy_decade_to_date(dt: &str) -> Option<(NaiveDate, Granularity)> {
+  let year = decade_to_year(dt)?;
+  NaiveDate::from_ymd_opt(year, 1, 1).map(|date| (date, Granularity::Decade))
+}
+
+/// Parse a date or date-time string as with fuzzy_to_datetime, also reporting the finest unit of time
+/// present in the source, e.g. Month for "2023-08" or Second for "2023-08-29 19:34:39", whatever defaults
+/// fill in the rest. Decades such as "1990s" are recognised too
+pub fn parse_with_granularity(dt: &str, date_opts: Option<DateOptions>) -> Option<(NaiveDateTime, Granularity)> {
+  let parsed = ParsedDateTime::parse(dt, date_opts)?;
+  let datetime = parsed.to_naive_datetime();
+  // truncate to milliseconds as fuzzy_to_datetime does
+  Some((datetime.with_nanosecond(datetime.nanosecond() / 1_000_000 * 1_000_000)?, parsed.granularity))
+}
+
 /// convert a date-time-like string to a valid ISO 8601-compatible date string
 /// for direct output or further processing via chrono
 /// If date_opts is None, the function will attempt to guess the date order with bias towards YMD and DMY in case of ambiguity
@@ -57,6 +203,12 @@ pub fn fuzzy_to_date_string(dt: &str, date_opts: Option<DateOptions>) -> Option<
   None
 }
 
+/// As fuzzy_to_date_string, but with the reason the string cannot be parsed,
+/// e.g. FuzzyDateError::MonthOutOfRange(13) for "2023-13-01"
+pub fn try_fuzzy_to_date_string(dt: &str, date_opts: Option<DateOptions>) -> Result<String, FuzzyDateError> {
+  fuzzy_to_date_string(dt, date_opts.clone()).ok_or_else(|| diagnose_failure(dt, date_opts).unwrap_or(FuzzyDateError::Unrecognised))
+}
+
 /// convert a date-like assuming the source string follows the Y-M-D pattern
 pub fn iso_fuzzy_to_date_string(dt: &str) -> Option<String> {
 	fuzzy_to_date_string(dt, Some(DateOptions::default()))
@@ -70,51 +222,232 @@ pub fn iso_fuzzy_to_datetime_string(dt: &str) -> Option<String> {
 
 /// convert a date-time-like string to a valid ISO 8601-compatible string
 pub fn fuzzy_to_date_string_with_time(dt: &str, date_opts: Option<DateOptions>) -> Option<(String, String, String)> {
-	
-  let (dt_opt, mtz_opt) = dt.to_start_end(".");
-  let has_mtz = if let Some(mtz) = mtz_opt {
-    segment_is_subseconds(mtz)
-  } else {
-    false
-  };
-  let milli_tz = if has_mtz {
-    mtz_opt.unwrap_or_default()
-  } else {
-    ""
-  }.to_string();
-  let dt_base = if has_mtz {
-    dt_opt.unwrap_or_default()
-  } else {
-    dt
-  }.to_string();
-	let clean_dt = dt_base.replace("T", " ").trim().to_string();
-	let mut dt_parts = clean_dt.split_whitespace();
-	let date_part = dt_parts.next().unwrap_or("0000-01-01");
-  let date_options = if let Some(dt_opts) = date_opts {
-    dt_opts
-  } else {
-    surmise_date_order_and_splitter(date_part)
+  fuzzy_to_date_string_with_optional_time(dt, date_opts)
+    .map(|(formatted_date, time_part, milli_tz)| (formatted_date, time_part.unwrap_or_else(|| "00:00:00".to_string()), milli_tz))
+}
+
+/// As above, but the time part is None if the source string only has a date
+fn fuzzy_to_date_string_with_optional_time(dt: &str, date_opts: Option<DateOptions>) -> Option<(String, Option<String>, String)> {
+  let date_opts = date_opts.unwrap_or_else(DateOptions::auto);
+  if dt.len() > date_opts.max_input_len() {
+    return None;
+  }
+  let stripped = strip_filler_words(dt, date_opts.filler_words());
+  let dt = stripped.as_deref().unwrap_or(dt);
+  if date_opts.strict_ascii_digits() && dt.chars().any(|c| c.is_numeric() && !c.is_ascii_digit()) {
+    return None;
+  }
+  if date_opts.require_t_separator() {
+    let trimmed = dt.trim();
+    let segments = split_datetime_segments(trimmed);
+    if segments.time.is_some() && !trimmed[segments.date.len()..].starts_with('T') {
+      return None;
+    }
+  }
+  let (formatted_date, time_part, milli_tz) = split_and_format_date_time(dt, &date_opts)
+    .or_else(|| (date_opts.guess_fallback() && !date_opts.is_auto()).then(|| split_and_format_date_time(dt, &date_opts.clone().with_guessing())).flatten())?;
+  if !date_opts.is_in_valid_range(&formatted_date) {
+    return None;
+  }
+  Some((formatted_date, time_part, milli_tz))
+}
+
+/// Split the source string into a formatted date, an optional time part and any subseconds with timezone suffix
+fn split_and_format_date_time(dt: &str, date_opts: &DateOptions) -> Option<(String, Option<String>, String)> {
+  // strip a trailing annotation such as a weekday in "2023-08-29 (Tue)" or a leading weekday
+  // as in the US long format "Tuesday, August 29, 2023"
+  let weekday_split = split_parenthetical_suffix(dt)
+    .map(|(base, annotation)| (base, alpha_weekday_to_index(annotation).or_else(|| weekday_alias_to_index(annotation, date_opts.weekday_aliases()))))
+    .or_else(|| split_leading_weekday(dt, date_opts.weekday_aliases()).map(|(weekday, rest)| (rest, Some(weekday))));
+  if let Some((base, weekday)) = weekday_split {
+    let date_time_parts = split_and_format_date_time(base, date_opts)?;
+    if date_opts.validate_weekday() {
+      if let Some(weekday) = weekday {
+        let date = NaiveDate::parse_from_str(&date_time_parts.0, "%Y-%m-%d").ok()?;
+        if date.weekday() != weekday {
+          return None;
+        }
+      }
+    }
+    return Some(date_time_parts);
+  }
+  // join a year grouped in thousands, e.g. "2 023", before whitespace splits the date from the time
+  if let Some(joined) = join_digit_groups(dt) {
+    return split_and_format_date_time(&joined, date_opts);
+  }
+  if let Some(moved) = move_leading_meridiem(dt) {
+    return split_and_format_date_time(&moved, date_opts);
+  }
+  // convert a time with an AM/PM marker, e.g. "2023-08-29 7:34:39 PM", to the 24-hour clock
+  if let Some((base, meridiem)) = split_meridiem(dt) {
+    let (formatted_date, time_part, milli_tz) = split_and_format_date_time(base, &date_opts.clone().with_clock(Clock::Auto))?;
+    let time_part = to_24_hour(&time_part?, Some(meridiem), date_opts.clock())?;
+    return Some((formatted_date, Some(time_part), milli_tz));
+  }
+  if date_opts.fractional_days() {
+    if let Some((formatted_date, time_part, milli_tz)) = fractional_day_to_date_and_time(dt, date_opts) {
+      return Some((formatted_date, Some(time_part), milli_tz));
+    }
+  }
+  if dt.contains_type(CharType::Alpha) {
+    if let Some((year, first_month, last_month)) = quarter_or_half_to_months(dt) {
+      let formatted_date = match date_opts.period_anchor() {
+        PeriodAnchor::Start => format!("{:04}-{:02}-01", year, first_month),
+        PeriodAnchor::End => format!("{:04}-{:02}-{:02}", year, last_month, days_in_month(year, last_month)),
+      };
+      return Some((formatted_date, None, "".to_string()));
+    }
+    // dates with unit markers are never read any other way
+    if starts_with_unit_marked_number(dt) {
+      let (iso_date, rest) = unit_marked_date_to_iso(dt)?;
+      let iso_dt = format!("{} {}", iso_date, rest);
+      return split_and_format_date_time(iso_dt.trim(), &date_opts.clone().with_format(DateOrder::YMD, Some('-')));
+    }
+    if let Some(rest) = strip_day_article(dt) {
+      return split_and_format_date_time(rest, date_opts);
+    }
+    if let Some(spaced) = space_joined_month_name_date(dt, date_opts.month_aliases()) {
+      return split_and_format_date_time(&spaced, date_opts);
+    }
+    let year_first = !date_opts.is_auto() && date_opts.order() == DateOrder::YMD;
+    if let Some((iso_date, rest)) = named_month_date_to_iso(dt, year_first, date_opts.month_aliases(), date_opts.two_digit_years()) {
+      let iso_dt = format!("{} {}", iso_date, rest);
+      return split_and_format_date_time(iso_dt.trim(), &date_opts.clone().with_format(DateOrder::YMD, Some('-')));
+    }
+  }
+  let segments = split_datetime_segments_by(dt, date_opts.number_locale());
+  let date_part = segments.date;
+  let date_options = date_opts.resolve(date_part);
+  // subseconds and any UTC designator or offset, e.g. 678Z in 19:34:39.678Z or +05:30 in 19:34:39+05:30
+  let milli_tz = format!("{}{}", segments.fraction.unwrap_or_default(), segments.zone.unwrap_or_default());
+  let time_part = match segments.time {
+    Some(tp) => Some(match time_keyword_to_time(tp) {
+      Some(keyword_time) => keyword_time.to_string(),
+      // bare hours may be rejected as ambiguous on a 12-hour clock
+      None => to_24_hour(tp, None, date_opts.clock())?,
+    }),
+    None => None,
   };
-	let time_part = dt_parts.next().unwrap_or("00:00:00");
 	if date_part.contains_type(CharType::Alpha) {
 			return None;
 	}
+  if date_options.strict_leading_zeros() && date_options.splitter().is_some_and(|splitter| has_excess_leading_zeros(date_part, splitter)) {
+    return None;
+  }
+  if date_options.consistent_splitter() && observed_splitters(date_part).len() > 1 {
+    return None;
+  }
+  if date_options.splitter() == Some('.') {
+    if let Some(formatted_date) = dotted_ordinal_date_to_iso(date_part) {
+      return Some((formatted_date, time_part, milli_tz));
+    }
+  }
+  if let Some(splitter) = date_options.splitter() {
+    if let Some(formatted_date) = two_part_date_to_iso(date_part, splitter, date_options.order(), date_options.two_part_dates(), date_options.period_anchor(), date_options.two_digit_years()) {
+      return Some((formatted_date, time_part, milli_tz));
+    }
+  }
 
-	to_formatted_date_string(date_part, date_options.order(), date_options.splitter()).map(|formatted_date| (formatted_date, time_part.to_string(), milli_tz))
+  let format_date = |order: DateOrder| to_formatted_date_string_anchored(date_part, order, date_options.splitter(), date_options.period_anchor(), date_options.two_digit_years());
+  let formatted_date = if date_options.compact_order_fallback() && date_options.splitter().is_none() {
+    let is_valid = |formatted_date: &String| NaiveDate::parse_from_str(formatted_date, "%Y-%m-%d").is_ok();
+    [date_options.order(), DateOrder::DMY, DateOrder::MDY, DateOrder::YMD].into_iter()
+      .find_map(|order| format_date(order).filter(is_valid))
+  } else {
+    format_date(date_options.order())
+  };
+  formatted_date.map(|formatted_date| (formatted_date, time_part, milli_tz))
 }
 
+/// Convert a date-like string with an optional time to separate chrono date and time values
+/// The time is None for date-only strings, rather than defaulting to midnight as with NaiveDateTime
+/// If date_opts is None, the date order and splitter will be guessed
+pub fn fuzzy_to_date_and_time(dt: &str, date_opts: Option<DateOptions>) -> Option<(NaiveDate, Option<NaiveTime>)> {
+  let parsed = ParsedDateTime::parse(dt, date_opts)?;
+  // the time is local to any UTC offset, which is ignored, and truncated to milliseconds
+  let time = parsed.time.and_then(|time| time.with_nanosecond(time.nanosecond() / 1_000_000 * 1_000_000));
+  Some((parsed.date, time))
+}
+
+/// Parse a time without a date, such as "19:34", "1934", "193439", "19:34:39.678Z", "7:15 pm" or "noon"
+/// Any UTC offset is ignored, so the time is local to it. An ISO 8601 time may have a leading designator, e.g. "T19:34:39"
+pub fn fuzzy_to_time(s: &str) -> Option<NaiveTime> {
+  if let Some(time) = s.trim().strip_prefix(['T', 't']).filter(|time| time.starts_with(|c: char| c.is_ascii_digit())) {
+    return fuzzy_to_time(time);
+  }
+  if let Some(moved) = move_leading_meridiem(s) {
+    return fuzzy_to_time(&moved);
+  }
+  let (base, meridiem) = match split_meridiem(s) {
+    Some((base, meridiem)) => (base, Some(meridiem)),
+    None => (s.trim(), None),
+  };
+  let (time, fraction, _) = split_time_segments(base, NumberLocale::Any);
+  let time = match time_keyword_to_time(time) {
+    Some(keyword_time) if meridiem.is_none() => keyword_time.to_string(),
+    _ => to_24_hour(time, meridiem, Clock::Auto)?,
+  };
+  let (formatted_time, ms_suffix) = fuzzy_to_formatted_time_parts(&time, fraction.unwrap_or_default(), None, true, '.', 3)?;
+  let millis = ms_suffix.get(..4).unwrap_or_default();
+  NaiveTime::parse_from_str(&format!("{}{}", formatted_time, millis), "%H:%M:%S%.3f").ok()
+}
+
+/// Split a full date followed by a fractional day, e.g. "2023-08-29.5", into the formatted date
+/// and the time of day represented by the fraction. Only a complete three-part date qualifies,
+/// so a trailing dot-separated component of a partial date is never mistaken for a fraction.
+fn fractional_day_to_date_and_time(dt: &str, date_opts: &DateOptions) -> Option<(String, String, String)> {
+  let (date_part, fraction) = dt.trim().rsplit_once('.')?;
+  if date_part.contains(|c: char| c.is_whitespace() || c == 'T') {
+    return None;
+  }
+  let date_options = date_opts.resolve(date_part);
+  let is_full_date = if let Some(split_char) = date_options.splitter() {
+    date_part.split(split_char).filter(|n| !n.is_empty() && n.is_digits_only()).count() == 3
+  } else {
+    date_part.len() == 8 && date_part.is_digits_only()
+  };
+  if !is_full_date {
+    return None;
+  }
+  let (time_part, millis) = fraction_of_day_to_time(fraction)?;
+  to_formatted_date_string_anchored(date_part, date_options.order(), date_options.splitter(), PeriodAnchor::Start, date_options.two_digit_years())
+    .map(|formatted_date| (formatted_date, time_part, millis))
+}
 
 /// convert a date-time-like string to a valid ISO 8601-compatible string
 pub fn fuzzy_to_datetime_string(dt: &str, date_opts: Option<DateOptions>, time_separator: Option<char>) -> Option<String> {
 	fuzzy_to_datetime_string_opts(dt, 'T', date_opts, time_separator, true)
 }
 
+/// As fuzzy_to_datetime_string, but with the reason the string cannot be parsed,
+/// e.g. FuzzyDateError::HourOutOfRange(25) for "2023-08-29 25:00"
+pub fn try_fuzzy_to_datetime_string(dt: &str, date_opts: Option<DateOptions>, time_separator: Option<char>) -> Result<String, FuzzyDateError> {
+  fuzzy_to_datetime_string(dt, date_opts.clone(), time_separator).ok_or_else(|| diagnose_failure(dt, date_opts).unwrap_or(FuzzyDateError::Unrecognised))
+}
+
 /// convert a date-time-like string to a valid ISO 8601-compatible string
 /// dt: the date-time string
 /// separator: the separator between the date and time parts
 /// add_z: whether to add 'Z' timezone indicator
 pub fn fuzzy_to_datetime_string_opts(dt: &str, separator: char, date_opts: Option<DateOptions>, time_separator: Option<char>, add_z: bool) -> Option<String> {
-  if let Some((formatted_date, time_part, ms_tz)) = fuzzy_to_date_string_with_time(dt, date_opts) {
+  let subsecond_separator = date_opts.as_ref().map_or('.', |opts| opts.subsecond_separator());
+  let subsecond_digits = date_opts.as_ref().map_or(3, |opts| opts.subsecond_digits());
+  if date_opts.as_ref().is_some_and(DateOptions::adjustments) {
+    if let Some((base, adjustment)) = split_trailing_adjustment(dt) {
+      let datetime = fuzzy_to_datetime(base, date_opts, time_separator).ok()?.checked_add_signed(adjustment)?;
+      let tz_suffix = if add_z { format!("{}{}Z", subsecond_separator, format_subseconds(datetime.nanosecond(), subsecond_digits)) } else { "".to_string() };
+      return Some(format!("{}{}{}{}", datetime.format("%Y-%m-%d"), separator, datetime.format("%H:%M:%S"), tz_suffix));
+    }
+  }
+  let preserve_date_only = date_opts.as_ref().is_some_and(|opts| opts.preserve_date_only());
+  let lenient_time = date_opts.as_ref().is_some_and(|opts| opts.lenient_time());
+  let normalize_to_utc = date_opts.as_ref().is_some_and(|opts| opts.normalize_to_utc());
+  if let Some((formatted_date, time_opt, ms_tz)) = fuzzy_to_date_string_with_optional_time(dt, date_opts) {
+    // don't fabricate a time and UTC zone for a date-only source string if requested
+    if time_opt.is_none() && preserve_date_only {
+      return Some(formatted_date);
+    }
+    let time_part = time_opt.unwrap_or_else(|| "00:00:00".to_string());
     // exclude the the whole date-time string if the time part is non-empty without digits
     if !time_part.is_empty() && !time_part.has_digits() {
       return None;
@@ -122,11 +455,20 @@ pub fn fuzzy_to_datetime_string_opts(dt: &str, separator: char, date_opts: Optio
     // fuzzy_to_formatted_time_parts failing here means a genuine, non-empty time chunk
     // was present but couldn't be parsed (e.g. "11.39" with a separator time_separator
     // doesn't recognise) -- time_part is *always* pre-filled to "00:00:00" upstream when
-    // no time was present at all, so that always parses fine and never reaches this. The
-    // previous `.unwrap_or_default()` here silently discarded a real parse failure and
-    // produced a malformed, dangling result like "2026-07-19T" (date, separator, nothing)
-    // instead of correctly failing the whole (date+time) parse.
-    let (formatted_time, tz_suffix) = fuzzy_to_formatted_time_parts(&time_part, &ms_tz, time_separator, add_z)?;
+    // no time was present at all, so that always parses fine and never reaches this.
+    // By default such a failure rejects the whole date-time, rather than silently
+    // discarding the time and producing a dangling result like "2026-07-19T" (date,
+    // separator, nothing). With lenient_time set, the unparseable time is replaced with
+    // midnight instead, keeping the valid date.
+    let (formatted_time, tz_suffix) = fuzzy_to_formatted_time_parts(&time_part, &ms_tz, time_separator, add_z, subsecond_separator, subsecond_digits)
+      .or_else(|| lenient_time.then(|| fuzzy_to_formatted_time_parts("00:00:00", "", None, add_z, subsecond_separator, subsecond_digits)).flatten())?;
+    let zone = ms_tz.trim_start_matches(|c: char| c.is_ascii_digit());
+    if let Some(offset) = Some(zone).filter(|zone| normalize_to_utc && zone.starts_with(['+', '-'])).and_then(utc_offset_minutes) {
+      let local = NaiveDateTime::parse_from_str(&format!("{} {}", formatted_date, formatted_time), "%Y-%m-%d %H:%M:%S").ok()?;
+      let utc = local - TimeDelta::minutes(offset as i64);
+      let tz_suffix = tz_suffix.strip_suffix(format_utc_offset(offset).as_str()).map_or(tz_suffix.clone(), |subseconds| format!("{}Z", subseconds));
+      return Some(format!("{}{}{}{}", utc.format("%Y-%m-%d"), separator, utc.format("%H:%M:%S"), tz_suffix));
+    }
     let formatted_str = format!("{}{}{}{}", formatted_date, separator, formatted_time, tz_suffix);
     if !formatted_str.is_empty() {
       return Some(formatted_str);
@@ -135,6 +477,33 @@ pub fn fuzzy_to_datetime_string_opts(dt: &str, separator: char, date_opts: Optio
   None
 }
 
+/// As fuzzy_to_datetime_string, but borrow the input if it is already normalised, e.g. "2023-08-29T19:34:39.678Z",
+/// allocating a new string only if normalisation changes it
+pub fn normalize_datetime_string(dt: &str, date_opts: Option<DateOptions>) -> Option<Cow<'_, str>> {
+  let trimmed = dt.trim();
+  let bytes = trimmed.as_bytes();
+  let is_normalized = bytes.len() == 24 && bytes[10] == b'T' && bytes[19] == b'.' && bytes[23] == b'Z'
+    && date_opts.as_ref().map_or_else(|| DateOptions::auto().accepts_canonical_iso(dt), |opts| opts.accepts_canonical_iso(dt) && opts.subsecond_separator() == '.')
+    && canonical_iso_to_datetime(trimmed).is_some_and(|datetime| date_opts.as_ref().is_none_or(|opts| opts.is_date_in_valid_range(&datetime.date())));
+  if is_normalized {
+    Some(Cow::Borrowed(trimmed))
+  } else {
+    fuzzy_to_datetime_string(dt, date_opts, None).map(Cow::Owned)
+  }
+}
+
+/// As fuzzy_to_date_string, but borrow the input if it is already a normalised date, e.g. "2023-08-29"
+pub fn normalize_date_string(dt: &str, date_opts: Option<DateOptions>) -> Option<Cow<'_, str>> {
+  let trimmed = dt.trim();
+  let is_normalized = date_opts.as_ref().map_or_else(|| DateOptions::auto().accepts_canonical_iso(dt), |opts| opts.accepts_canonical_iso(dt))
+    && canonical_iso_to_date(trimmed).is_some_and(|date| date_opts.as_ref().is_none_or(|opts| opts.is_date_in_valid_range(&date)));
+  if is_normalized {
+    Some(Cow::Borrowed(trimmed))
+  } else {
+    fuzzy_to_date_string(dt, date_opts).map(Cow::Owned)
+  }
+}
+
 // Check if a string is likely to be a date string with an optional time component
 pub fn is_datetime_like(text: &str) -> bool {
   fuzzy_to_datetime_string(text, None, None).is_some()
@@ -142,7 +511,9 @@ pub fn is_datetime_like(text: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use guess::surmise_date_order;
+    use guess::{guess_date_order, guess_date_splitter, surmise_date_order, DateOrderGuess};
+    use validators::segment_is_subseconds;
+    use to_segments::ToSegments;
 
   use super::*;
 
@@ -183,6 +554,32 @@ mod tests {
       );
   }
 
+  #[test]
+  fn test_dotted_dates_keep_their_year() {
+      // the year after the last dot of a date-only string is never a subsecond candidate
+      assert!(!segment_is_subseconds("2023"));
+      assert_eq!(fuzzy_to_date_string("29.08.2023", None), Some("2023-08-29".to_string()));
+      assert_eq!(fuzzy_to_date_string("29.08.2023", Some(DateOptions::dmy('.'))), Some("2023-08-29".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      // while a fraction after a time with a dotted date is still read as subseconds
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39.678", None, None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39.7", Some(DateOptions::dmy('.')), None), Some("2023-08-29T19:34:39.700Z".to_string()));
+  }
+
+  #[test]
+  fn test_phone_numbers_and_ids_are_not_dates() {
+      assert_eq!(guess_date_order("555-1234", Some('-')), DateOrderGuess::NonDate);
+      assert_eq!(guess_date_order("12-3456-7890", Some('-')), DateOrderGuess::NonDate);
+      assert_eq!(guess_date_order("2023-45-01", Some('-')), DateOrderGuess::NonDate);
+      assert_eq!(guess_date_order("31-32-2023", Some('-')), DateOrderGuess::NonDate);
+      assert_eq!(guess_date_order("12-345-2023", Some('-')), DateOrderGuess::NonDate);
+      assert_eq!(fuzzy_to_date_string("12-3456-7890", None), None);
+      // genuine dates are unaffected
+      assert_eq!(guess_date_order("2023-08-29", Some('-')), DateOrderGuess::YearFirst);
+      assert_eq!(guess_date_order("08/29/2023", Some('/')), DateOrderGuess::MonthFirst);
+      assert_eq!(guess_date_order("29.08.2023", Some('.')), DateOrderGuess::DayFirst);
+  }
+
   #[test]
   fn test_dot_separated_dates_are_recognised_under_guessing() {
       // Regression: segment_is_subseconds misread a bare 4-digit year (e.g. "2026" from
@@ -206,6 +603,31 @@ mod tests {
       }
   }
 
+  #[test]
+  fn test_dotted_ordinal_dates() {
+      assert_eq!(fuzzy_to_date_string("2023.241", None), Some("2023-08-29".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023.241 19:34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      // 2 digits up to 12 are still months
+      assert_eq!(fuzzy_to_date_string("2023.08", None), Some("2023-08-01".to_string()));
+      assert_eq!(fuzzy_to_date_string("2023.366", None), None);
+      assert_eq!(fuzzy_to_date_string("2023.241", Some(DateOptions::default())), None);
+  }
+
+  #[test]
+  fn test_dot_separated_dates_with_colon_separated_times() {
+      assert_eq!(
+          fuzzy_to_datetime_string("2023.08.29 19:34:39", None, None),
+          Some("2023-08-29T19:34:39.000Z".to_string())
+      );
+      assert_eq!(
+          fuzzy_to_datetime_string("2023.08.29 19:34:39.678", None, None),
+          Some("2023-08-29T19:34:39.678Z".to_string())
+      );
+      // the date's last dot is never a subsecond boundary, even when followed by 3 digits
+      assert_eq!(fuzzy_to_date_string("2023.08.123", None), None);
+      assert_eq!(fuzzy_to_date_string("2023.08.29", None), Some("2023-08-29".to_string()));
+  }
+
   #[test]
   fn test_a_genuinely_unparseable_time_component_fails_the_whole_datetime_rather_than_producing_a_dangling_result() {
       // Regression: fuzzy_to_datetime_string_opts used `.unwrap_or_default()` on a
@@ -223,6 +645,329 @@ mod tests {
       );
   }
 
+  #[test]
+  fn test_fuzzy_to_date_and_time() {
+      let (date, time) = fuzzy_to_date_and_time("29/08/2023", None).unwrap();
+      assert_eq!(date, NaiveDate::from_ymd_opt(2023, 8, 29).unwrap());
+      assert_eq!(time, None);
+
+      let (date, time) = fuzzy_to_date_and_time("2023-08-29 19:34:39.678", None).unwrap();
+      assert_eq!(date, NaiveDate::from_ymd_opt(2023, 8, 29).unwrap());
+      assert_eq!(time, NaiveTime::from_hms_milli_opt(19, 34, 39, 678));
+
+      // an explicit midnight is still reported as a time
+      let (_, time) = fuzzy_to_date_and_time("2023-08-29 00:00", None).unwrap();
+      assert_eq!(time, NaiveTime::from_hms_opt(0, 0, 0));
+
+      assert_eq!(fuzzy_to_date_and_time("2023-08-29 25:00", None), None);
+  }
+
+  #[test]
+  fn test_fractional_days() {
+      let opts = DateOptions::default().with_fractional_days(true);
+      assert_eq!(
+          fuzzy_to_datetime_string("2023-08-29.5", Some(opts.clone()), None),
+          Some("2023-08-29T12:00:00.000Z".to_string())
+      );
+      assert_eq!(
+          fuzzy_to_datetime_string("2023-08-29.25", Some(opts), None),
+          Some("2023-08-29T06:00:00.000Z".to_string())
+      );
+      // also available when guessing the date order and splitter
+      let auto_opts = DateOptions::auto().with_fractional_days(true);
+      assert_eq!(
+          fuzzy_to_datetime_string("29.08.2023.75", Some(auto_opts.clone()), None),
+          Some("2023-08-29T18:00:00.000Z".to_string())
+      );
+      // a partial dot-separated date is not a fractional day
+      assert_eq!(fuzzy_to_date_string("19.07.2026", Some(auto_opts)), Some("2026-07-19".to_string()));
+      // 2-digit years follow the configured rule
+      let pivot_opts = DateOptions::dmy('-').with_fractional_days(true).with_pivot(50);
+      assert_eq!(
+          fuzzy_to_datetime_string("28-02-55.5", Some(pivot_opts), None),
+          Some("1955-02-28T12:00:00.000Z".to_string())
+      );
+      // without the option, the fraction is never read as a time of day
+      assert_ne!(
+          fuzzy_to_datetime_string("2023-08-29.5", None, None),
+          Some("2023-08-29T12:00:00.000Z".to_string())
+      );
+  }
+
+  #[test]
+  fn test_short_fractions_of_a_second_are_scaled_by_position() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.7", None, None), Some("2023-08-29T19:34:39.700Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.07", None, None), Some("2023-08-29T19:34:39.070Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.007", None, None), Some("2023-08-29T19:34:39.007Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39.7Z", None, None), Some("2023-08-29T19:34:39.700Z".to_string()));
+      // dot-separated times are unaffected
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19.34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_dot_separated_times() {
+      // neither fractional hours nor subseconds
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19.34.39", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19.34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19.34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19.34.39", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+      // subseconds need 3 or more digits after a dot-separated time
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19.34.39.678Z", None, None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      // or with the dot as a fixed time separator
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19.34.39", None, Some('.')), Some("2023-08-29T19:34:39.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_compact_iso_datetimes() {
+      let samples = [
+        ("20230829T193439.678Z", "2023-08-29T19:34:39.678Z"),
+        ("20230829T193439.7Z", "2023-08-29T19:34:39.700Z"),
+        ("20230829T193439,67Z", "2023-08-29T19:34:39.670Z"),
+        ("20230829T193439.678+0530", "2023-08-29T19:34:39.678+05:30"),
+        ("20230829T193439Z", "2023-08-29T19:34:39.000Z"),
+      ];
+      for (input, expected) in samples {
+        let output = fuzzy_to_datetime_string(input, None, None);
+        assert_eq!(output.as_deref(), Some(expected), "parsing {:?}", input);
+        // the output reads back unchanged
+        assert_eq!(fuzzy_to_datetime_string(expected, None, None).as_deref(), Some(expected));
+      }
+      assert_eq!(fuzzy_to_time("T193439.678Z"), NaiveTime::from_hms_milli_opt(19, 34, 39, 678));
+  }
+
+  #[test]
+  fn test_version_numbers_are_not_times() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 1.2.345", None, None), None);
+      assert_eq!(fuzzy_to_time("1.2.345"), None);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19.34.678", None, None), Some("2023-08-29T19:34:00.678Z".to_string()));
+  }
+
+  #[test]
+  fn test_excess_subsecond_digits() {
+      // digits beyond nanoseconds are truncated
+      let opts = DateOptions::auto().with_subsecond_digits(9);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.123456789012", Some(opts.clone()), None), Some("2023-08-29T19:34:39.123456789Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.123456789012+02:00", Some(opts), None), Some("2023-08-29T19:34:39.123456789+02:00".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.123456789012", None, None), Some("2023-08-29T19:34:39.123Z".to_string()));
+      let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_nano_opt(19, 34, 39, 123_456_789).unwrap();
+      assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 19:34:39.123456789012", None), Some(expected));
+  }
+
+  #[test]
+  fn test_fractional_minutes() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34.5", None, None), Some("2023-08-29T19:34:30.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34.75", None, Some(':')), Some("2023-08-29T19:34:45.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_subsecond_separator() {
+      let opts = DateOptions::default().with_subsecond_separator(',');
+      assert_eq!(
+          fuzzy_to_datetime_string("2023-08-29 19:34:39", Some(opts.clone()), None),
+          Some("2023-08-29T19:34:39,000Z".to_string())
+      );
+      assert_eq!(
+          fuzzy_to_datetime_string("2023-08-29T19:34:39.678Z", Some(opts.clone()), None),
+          Some("2023-08-29T19:34:39,678Z".to_string())
+      );
+      // the default is unchanged and chrono conversion is unaffected
+      assert_eq!(
+          fuzzy_to_datetime_string("2023-08-29 19:34:39", None, None),
+          Some("2023-08-29T19:34:39.000Z".to_string())
+      );
+      assert!(fuzzy_to_datetime("2023-08-29 19:34:39", Some(opts), None).is_ok());
+  }
+
+  #[test]
+  fn test_preserve_date_only() {
+      let opts = DateOptions::default().with_preserve_date_only(true);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29", Some(opts.clone()), None), Some("2023-08-29".to_string()));
+      assert_eq!(
+          fuzzy_to_datetime_string("2023-08-29 19:34", Some(opts.clone()), None),
+          Some("2023-08-29T19:34:00.000Z".to_string())
+      );
+      // the default behaviour still fills in midnight UTC
+      assert_eq!(iso_fuzzy_to_datetime_string("2023-08-29"), Some("2023-08-29T00:00:00.000Z".to_string()));
+      assert!(fuzzy_to_datetime("2023-08-29", Some(opts), None).is_ok());
+  }
+
+  #[test]
+  fn test_strict_leading_zeros() {
+      assert_eq!(fuzzy_to_date_string("2023-008-29", None), Some("2023-08-29".to_string()));
+      let opts = DateOptions::auto().with_strict_leading_zeros(true);
+      assert_eq!(fuzzy_to_date_string("2023-008-29", Some(opts.clone())), None);
+      assert_eq!(fuzzy_to_date_string("29/08/02023", Some(opts.clone())), None);
+      assert_eq!(fuzzy_to_date_string("2023-08-09", Some(opts.clone())), Some("2023-08-09".to_string()));
+      assert_eq!(fuzzy_to_date_string("9.8.2023", Some(opts)), Some("2023-08-09".to_string()));
+  }
+
+  #[test]
+  fn test_number_locales() {
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39,678", None, None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      let opts_comma = DateOptions::auto().with_number_locale(NumberLocale::DecimalComma);
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39,678", Some(opts_comma.clone()), None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      // a decimal point is no longer a fraction separator
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39.678", Some(opts_comma.clone()), None), None);
+      // even in canonical ISO date-times
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39.678Z", Some(opts_comma.clone()), None), None);
+      assert!(fuzzy_to_datetime("2023-08-29T19:34:39.678", Some(opts_comma.clone()), None).is_err());
+      assert_eq!(normalize_datetime_string("2023-08-29T19:34:39.678Z", Some(opts_comma.clone())), None);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39,678Z", Some(opts_comma), None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      let opts_point = DateOptions::auto().with_number_locale(NumberLocale::DecimalPoint);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.678", Some(opts_point), None), Some("2023-08-29T19:34:39.678Z".to_string()));
+  }
+
+  #[test]
+  fn test_space_grouped_years() {
+      assert_eq!(fuzzy_to_date_string("2 023", None), Some("2023-01-01".to_string()));
+      // not split into a date of 2 and a time of 023
+      assert_eq!(fuzzy_to_datetime_string("29 Aug 2 023", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29 Aug 2 023 19:34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_strict_ascii_digits() {
+      let opts = DateOptions::auto().with_strict_ascii_digits(true);
+      assert_eq!(fuzzy_to_date_string("2023-08-29", Some(opts.clone())), Some("2023-08-29".to_string()));
+      // Arabic-Indic, fullwidth and superscript digits are disqualifying
+      assert_eq!(fuzzy_to_date_string("٢٠٢٣-٠٨-٢٩", Some(opts.clone())), None);
+      assert_eq!(fuzzy_to_date_string("２０２３-０８-２９", Some(opts.clone())), None);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-2⁹ 19:34", Some(opts), None), None);
+  }
+
+  #[test]
+  fn test_clock_modes() {
+      for clock in [Clock::Auto, Clock::Clock12, Clock::Clock24] {
+          let opts = DateOptions::auto().with_clock(clock);
+          assert_eq!(fuzzy_to_datetime_string("2023-08-29 13:00", Some(opts), None), Some("2023-08-29T13:00:00.000Z".to_string()));
+      }
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:15 PM", None, None), Some("2023-08-29T19:15:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 07:15", None, None), Some("2023-08-29T07:15:00.000Z".to_string()));
+      let opts_12 = DateOptions::auto().with_clock(Clock::Clock12);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:15pm", Some(opts_12.clone()), None), Some("2023-08-29T19:15:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 noon", Some(opts_12.clone()), None), Some("2023-08-29T12:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:15", Some(opts_12.clone()), None), None);
+      // canonical ISO times need a marker too
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T07:15:00", Some(opts_12.clone()), None), None);
+      assert!(fuzzy_to_datetime("2023-08-29T07:15:00", Some(opts_12.clone()), None).is_err());
+      assert_eq!(normalize_datetime_string("2023-08-29T07:15:00.000Z", Some(opts_12)), None);
+      let opts_24 = DateOptions::auto().with_clock(Clock::Clock24);
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:15", Some(opts_24.clone()), None), Some("2023-08-29T07:15:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:15 PM", Some(opts_24), None), None);
+  }
+
+  #[test]
+  fn test_meridiem_times() {
+      // midnight, noon and a single-digit PM hour
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 12:00 am", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 12:00 pm", None, None), Some("2023-08-29T12:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:34:39 PM", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 07:34 am", None, None), Some("2023-08-29T07:34:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:34 p.m.", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      // hours beyond the 12-hour clock cannot carry a marker
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 13:34 pm", None, None), None);
+      assert_eq!(try_fuzzy_to_datetime_string("2023-08-29 19:34 AM", None, None), Err(FuzzyDateError::HourOutOfRange(19)));
+  }
+
+  #[test]
+  fn test_database_datetimes() {
+      // MySQL
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+      // Postgres with microseconds and an hours-only offset
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.678901", None, None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.678901+00", None, None), Some("2023-08-29T19:34:39.678+00:00".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39+02", None, None), Some("2023-08-29T19:34:39.000+02:00".to_string()));
+      let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_milli_opt(19, 34, 39, 678).unwrap();
+      assert_eq!(fuzzy_to_datetime("2023-08-29 19:34:39.678901+00", None, None), Ok(expected));
+      assert_eq!(fuzzy_to_datetime("2023-08-29 21:34:39.678+02", None, None), Ok(expected));
+      // the date order of a dotted date is unaffected
+      assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39.678901", None, None), Some("2023-08-29T19:34:39.678Z".to_string()));
+      let date_opts = detect_date_format_from_list(&["2023-08-29 19:34:39.678901+00", "2023-08-30 07:05:00.000001+00"]);
+      assert_eq!(date_opts.order(), DateOrder::YMD);
+      assert_eq!(date_opts.splitter(), Some('-'));
+  }
+
+  #[test]
+  fn test_reduced_precision_offsets() {
+      // hours-only offsets are padded with 00 minutes
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39+05", None, None), Some("2023-08-29T19:34:39.000+05:00".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39-08", None, None), Some("2023-08-29T19:34:39.000-08:00".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39+0530", None, None), Some("2023-08-29T19:34:39.000+05:30".to_string()));
+      let expected = NaiveDate::from_ymd_opt(2023, 8, 30).unwrap().and_hms_opt(3, 34, 39).unwrap();
+      assert_eq!(fuzzy_to_datetime("2023-08-29T19:34:39-08", None, None), Ok(expected));
+  }
+
+  #[test]
+  fn test_numeric_utc_offsets() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39-08:00", None, None), Some("2023-08-29T19:34:39.000-08:00".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39-0800", None, None), Some("2023-08-29T19:34:39.000-08:00".to_string()));
+      // normalised to UTC by fuzzy_to_datetime
+      let expected = NaiveDate::from_ymd_opt(2023, 8, 30).unwrap().and_hms_opt(3, 34, 39).unwrap();
+      assert_eq!(fuzzy_to_datetime("2023-08-29T19:34:39-08:00", None, None), Ok(expected));
+      assert_eq!(fuzzy_to_datetime("2023-08-30T09:04:39+05:30", None, None), Ok(expected));
+      // Z and a zero offset are the same instant
+      assert_eq!(fuzzy_to_datetime("2023-08-29T19:34:39Z", None, None), fuzzy_to_datetime("2023-08-29T19:34:39+00:00", None, None));
+  }
+
+  #[test]
+  fn test_canonical_iso_fast_path() {
+      let samples = ["2023-08-29T19:34:39Z", "2023-08-29T19:34:39.678Z", "2023-08-29 19:34:39.678901", "2023-08-29T19:34:39.5"];
+      for sample in samples {
+          let fast = fuzzy_to_datetime(sample, None, None);
+          let formatted = fuzzy_to_datetime_string(sample, None, None).unwrap();
+          let fuzzy = NaiveDateTime::parse_from_str(&formatted, "%Y-%m-%dT%H:%M:%S%.3fZ");
+          assert_eq!(fast, fuzzy);
+      }
+      // options that would read the input differently bypass the fast path
+      let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+      let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+      let opts = DateOptions::auto().with_valid_range(start, end);
+      assert!(fuzzy_to_datetime("2023-08-29T19:34:39Z", Some(opts), None).is_err());
+      assert!(fuzzy_to_datetime("2023-08-29T19:34:39Z", Some(DateOptions::dmy('-')), None).is_err());
+  }
+
+  #[test]
+  fn test_normalize_borrows_normalised_input() {
+      assert!(matches!(normalize_datetime_string("2023-08-29T19:34:39.678Z", None), Some(Cow::Borrowed("2023-08-29T19:34:39.678Z"))));
+      assert!(matches!(normalize_date_string(" 2023-08-29 ", None), Some(Cow::Borrowed("2023-08-29"))));
+      let normalized = normalize_datetime_string("29/08/2023 19:34:39.678", None);
+      assert!(matches!(normalized, Some(Cow::Owned(_))));
+      assert_eq!(normalized.as_deref(), Some("2023-08-29T19:34:39.678Z"));
+      assert!(matches!(normalize_datetime_string("2023-08-29T19:34:39Z", None), Some(Cow::Owned(_))));
+      assert_eq!(normalize_date_string("29.08.2023", None).as_deref(), Some("2023-08-29"));
+      assert_eq!(normalize_date_string("2023-08-29", Some(DateOptions::dmy('/'))), None);
+  }
+
+  #[test]
+  fn test_parenthetical_weekdays() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 (Tue)", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29/08/2023 19:34 (Tuesday)", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      // the weekday is only checked if requested
+      assert_eq!(fuzzy_to_date_string("2023-08-29 (Wed)", None), Some("2023-08-29".to_string()));
+      let opts = DateOptions::auto().with_validate_weekday(true);
+      assert_eq!(fuzzy_to_date_string("2023-08-29 (Tue)", Some(opts.clone())), Some("2023-08-29".to_string()));
+      assert_eq!(fuzzy_to_date_string("2023-08-29 (Wed)", Some(opts)), None);
+  }
+
+  #[test]
+  fn test_us_long_format_with_weekday() {
+      assert_eq!(fuzzy_to_date_string("Tuesday, August 29, 2023", None), Some("2023-08-29".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("Tue, Aug 29, 2023 19:34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      let opts = DateOptions::auto().with_validate_weekday(true);
+      assert_eq!(fuzzy_to_date_string("Wednesday, August 29, 2023", Some(opts)), None);
+  }
+
+  #[test]
+  fn test_time_keywords() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 EOD", None, None), Some("2023-08-29T23:59:59.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 SOD", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 bod", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 noon", None, None), Some("2023-08-29T12:00:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 Midnight", None, None), Some("2023-08-29T00:00:00.000Z".to_string()));
+      // other words in the time position are still rejected
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 later", None, None), None);
+  }
+
   #[test]
   fn test_is_datetime_like() {
       assert!(is_datetime_like("2023-10-10T10:10:10"));
@@ -265,6 +1010,32 @@ mod tests {
     assert_eq!(date_opts_2.splitter(), Some('/'));
   }
 
+  #[test]
+  fn test_splitter_guessing_ignores_the_time_component() {
+    assert_eq!(guess_date_splitter("2023-08-29T19:34:39"), Some('-'));
+    assert_eq!(guess_date_splitter("29/08/2023 19.34.39"), Some('/'));
+    assert_eq!(guess_date_splitter("20230829 19.34.39"), None);
+    assert_eq!(guess_date_splitter("20230829T193439"), None);
+
+    let date_opts = surmise_date_order_and_splitter("29/08/2023 19:34:39");
+    assert_eq!(date_opts.order(), DateOrder::DMY);
+    assert_eq!(date_opts.splitter(), Some('/'));
+
+    let date_opts = surmise_date_order_and_splitter("20230829T19:34:39");
+    assert_eq!(date_opts.order(), DateOrder::YMD);
+    assert_eq!(date_opts.splitter(), None);
+  }
+
+  #[test]
+  fn test_splitter_priority() {
+    // by default the first separator found wins
+    assert_eq!(DateOptions::auto().resolve("2023-08.29").splitter(), Some('-'));
+    let dot_first = DateOptions::auto().with_splitter_priority(&['.', '-', '/']);
+    assert_eq!(dot_first.resolve("2023-08.29").splitter(), Some('.'));
+    assert_eq!(dot_first.resolve("2023-08-29").splitter(), Some('-'));
+    assert_eq!(fuzzy_to_date_string("29.08.2023", Some(dot_first)), Some("2023-08-29".to_string()));
+  }
+
   #[test]
   fn test_surmise_date_order_and_splitter_plain() {
 
@@ -354,6 +1125,17 @@ mod tests {
     assert_eq!(date_opts_iso.order(), DateOrder::YMD);
     assert_eq!(date_opts_iso.splitter(), Some('-'));
 
+    // a lowercase time designator leaves the date portion intact
+    let sample_datetimes_dmy = vec![
+      "08-07-1998t19:34",
+      "10-09-2021t07:05:00",
+      "15-12-2022t23:59",
+    ];
+    let date_opts_lc = detect_date_format_from_list(&sample_datetimes_dmy);
+    assert_eq!(date_opts_lc.order(), DateOrder::DMY);
+    assert_eq!(date_opts_lc.splitter(), Some('-'));
+    assert_eq!(fuzzy_to_datetime_string("15-12-2022t23:59", Some(date_opts_lc), None), Some("2022-12-15T23:59:00.000Z".to_string()));
+
 
     struct SpecialDay {
       #[allow(dead_code)]
@@ -380,6 +1162,603 @@ mod tests {
     assert_eq!(date_opts_special.order(), DateOrder::MDY);
   }
 
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_detect_date_format_from_json() {
+    let rows: Vec<serde_json::Value> = serde_json::from_str(r#"[
+      { "name": "Independence Day", "date": "07/04/1776" },
+      { "name": "Unknown" },
+      { "name": "Christmas Day", "date": "12/25/2021" }
+    ]"#).unwrap();
+    let date_opts = detect_date_format_from_json(&rows, "date");
+    assert_eq!(date_opts.order(), DateOrder::MDY);
+    assert_eq!(date_opts.splitter(), Some('/'));
+  }
+
+  #[test]
+  fn test_detection_skips_durations_and_times() {
+    // "23:59:59" would otherwise look like an unambiguous D/M/Y date
+    let sample_dates = vec!["", "23:59:59", "P1W", "PT36H", "07/04/1776", "12/25/2021"];
+    let date_opts = detect_date_format_from_list(&sample_dates);
+    assert_eq!(date_opts.order(), DateOrder::MDY);
+    assert_eq!(date_opts.splitter(), Some('/'));
+  }
+
+  #[test]
+  fn test_detection_ignores_utc_offsets() {
+    // neither the sign nor the digits of an offset are read as date components
+    let date_opts = detect_date_format_from_list(&["08-07-2023T19:34:39+0530", "29-08-2023T07:05:00-0800"]);
+    assert_eq!(date_opts.order(), DateOrder::DMY);
+    assert_eq!(date_opts.splitter(), Some('-'));
+    let date_opts = detect_date_format_from_list(&["08/07/2023 19:34:39+05:30", "08/29/2023 07:05:00-08"]);
+    assert_eq!(date_opts.order(), DateOrder::MDY);
+    assert_eq!(date_opts.splitter(), Some('/'));
+    let date_opts = detect_date_format_from_list(&["2023-08-07T19:34:39-0800"]);
+    assert_eq!(date_opts.order(), DateOrder::YMD);
+  }
+
+  #[test]
+  fn test_detect_date_format_from_columns() {
+    let start_dates = ["01/02/2023", "03/04/2023"];
+    let end_dates = ["05/06/2023", "29/08/2023"];
+    let date_opts = detect_date_format_from_columns(&[&start_dates, &end_dates]);
+    assert_eq!(date_opts.order(), DateOrder::DMY);
+    assert_eq!(date_opts.splitter(), Some('/'));
+    // the ambiguous column alone is inconclusive
+    assert_eq!(detect_date_format_from_columns(&[&start_dates]).splitter(), None);
+  }
+
+  #[test]
+  fn test_detection_by_consensus() {
+    // the discriminating row comes last
+    let mdy = ["01/02/2023", "03/04/2023", "05/06/2023", "07/08/2023", "12/25/2023"];
+    assert_eq!(detect_date_format_from_list(&mdy), DateOptions::new(DateOrder::MDY, Some('/')));
+    // a single typo row doesn't flip the majority
+    let dmy_with_typo = ["12/25/2023", "29/08/2023", "30/08/2023", "31/08/2023"];
+    assert_eq!(detect_date_format_from_list(&dmy_with_typo).order(), DateOrder::DMY);
+    let tally = tally_date_orders(&dmy_with_typo);
+    assert_eq!(tally, DateOrderTally { year_first: 0, day_first: 3, month_first: 1, day_or_month_first: 0 });
+    assert!(tally.is_conflicting());
+    assert_eq!(tally.confidence(), 0.75);
+    // ties favour day-first if some rows are ambiguous
+    let tied = tally_date_orders(&["12/25/2023", "29/08/2023", "05/06/2023"]);
+    assert_eq!(tied.majority_order(), Some(DateOrder::DMY));
+    assert_eq!(tally_date_orders(&["05/06/2023", ""]).majority_order(), None);
+    assert!(!tally_date_orders(&mdy).is_conflicting());
+  }
+
+  #[test]
+  fn test_is_detection_ambiguous() {
+    assert!(is_detection_ambiguous(&["05/06/2023", "01/02/2023", "12/11/2023"]));
+    assert!(is_detection_ambiguous(&[]));
+    assert!(!is_detection_ambiguous(&["05/06/2023", "01/02/2023", "25/11/2023"]));
+    assert!(!is_detection_ambiguous(&["05/06/2023", "2023-08-29"]));
+  }
+
+  #[test]
+  fn test_detect_date_formats_by_splitter() {
+    let mixed = ["2023-08-29", "05/06/2023", "2023-08-30", "29/08/2023", "31/08/2023 19:34"];
+    assert_eq!(detect_date_formats_by_splitter(&mixed), vec![DateOptions::new(DateOrder::YMD, Some('-')), DateOptions::new(DateOrder::DMY, Some('/'))]);
+    // the two orders tie across the whole list, so the ambiguous row favours day-first
+    assert_eq!(detect_date_format_from_list(&mixed), DateOptions::new(DateOrder::DMY, Some('/')));
+    assert_eq!(detect_date_formats_by_splitter(&["29/08/2023", "30/08/2023"]).len(), 1);
+    // an inconclusive group is omitted
+    assert_eq!(detect_date_formats_by_splitter(&["2023-08-29", "05/06/2023"]), vec![DateOptions::new(DateOrder::YMD, Some('-'))]);
+  }
+
+  #[test]
+  fn test_looks_like_date_column() {
+    let mostly_dates = vec!["2021-09-10", "2022-12-15", "", "n/a", "1999-11-09 10:30", "1998-07-08"];
+    assert!(looks_like_date_column(&mostly_dates, 0.8));
+
+    let mostly_text = vec!["apples", "pears", "2021-09-10", "oranges"];
+    assert!(!looks_like_date_column(&mostly_text, 0.5));
+
+    assert!(!looks_like_date_column(&["", " "], 0.5));
+  }
+
+  #[test]
+  fn test_parse_format_hint() {
+    let dmy = parse_format_hint("dd/mm/yyyy").unwrap();
+    assert_eq!(dmy.order(), DateOrder::DMY);
+    assert_eq!(dmy.splitter(), Some('/'));
+
+    let mdy = parse_format_hint("Date (MM/DD/YYYY)").unwrap();
+    assert_eq!(mdy.order(), DateOrder::MDY);
+    assert_eq!(mdy.splitter(), Some('/'));
+
+    let de = parse_format_hint("Geburtsdatum d.m.yy").unwrap();
+    assert_eq!(de.order(), DateOrder::DMY);
+    assert_eq!(de.splitter(), Some('.'));
+
+    let fixed = parse_format_hint("yyyymmdd").unwrap();
+    assert_eq!(fixed.order(), DateOrder::YMD);
+    assert_eq!(fixed.splitter(), None);
+
+    assert_eq!(parse_format_hint("Date of birth"), None);
+    assert_eq!(parse_format_hint("dd-mm/yyyy"), None);
+  }
+
+  #[test]
+  fn test_unit_suffixed_times() {
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 19h34m39s", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("29/08/2023 19h34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_time("19h34"), NaiveTime::from_hms_opt(19, 34, 0));
+  }
+
+  #[test]
+  fn test_chinese_meridiem_markers() {
+      assert_eq!(fuzzy_to_time("下午 7:15"), NaiveTime::from_hms_opt(19, 15, 0));
+      assert_eq!(fuzzy_to_time("上午12:30"), NaiveTime::from_hms_opt(0, 30, 0));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 下午 7:15", None, None), Some("2023-08-29T19:15:00.000Z".to_string()));
+      assert_eq!(fuzzy_to_datetime_string("2023-08-29 7:15 下午", None, None), Some("2023-08-29T19:15:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_fuzzy_to_time() {
+    let time = |h, m, s, ms| NaiveTime::from_hms_milli_opt(h, m, s, ms);
+    assert_eq!(fuzzy_to_time("1934"), time(19, 34, 0, 0));
+    assert_eq!(fuzzy_to_time("193439"), time(19, 34, 39, 0));
+    assert_eq!(fuzzy_to_time("19:34:39.678Z"), time(19, 34, 39, 678));
+    assert_eq!(fuzzy_to_time("7:15 pm"), time(19, 15, 0, 0));
+    assert_eq!(fuzzy_to_time("noon"), time(12, 0, 0, 0));
+    assert_eq!(fuzzy_to_time("19345"), None);
+    assert_eq!(fuzzy_to_time("25:00"), None);
+    assert_eq!(fuzzy_to_time(",13²"), None);
+    assert_eq!(fuzzy_to_datetime_string("20230829 1934", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_parse_if_unambiguous() {
+    assert_eq!(parse_if_unambiguous("15/08/2023", '/'), NaiveDate::from_ymd_opt(2023, 8, 15));
+    assert_eq!(parse_if_unambiguous("08/15/2023", '/'), NaiveDate::from_ymd_opt(2023, 8, 15));
+    assert_eq!(parse_if_unambiguous("08/07/2023", '/'), None);
+    // the same either way
+    assert_eq!(parse_if_unambiguous("08.08.2023", '.'), NaiveDate::from_ymd_opt(2023, 8, 8));
+    assert_eq!(parse_if_unambiguous("32/08/2023", '/'), None);
+  }
+
+  #[test]
+  fn test_parse_with_allowed_formats() {
+    let allowed = [DateOptions::ymd('-'), DateOptions::dmy('/')];
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29);
+    assert_eq!(parse_with_allowed_formats("2023-08-29", &allowed), expected);
+    assert_eq!(parse_with_allowed_formats("29/08/2023 19:34", &allowed), expected);
+    // other splitters, orders and partial dates are rejected
+    assert_eq!(parse_with_allowed_formats("29.08.2023", &allowed), None);
+    assert_eq!(parse_with_allowed_formats("29-08-2023", &allowed), None);
+    assert_eq!(parse_with_allowed_formats("2023/08/29", &allowed), None);
+    assert_eq!(parse_with_allowed_formats("2023-08", &allowed), None);
+    assert_eq!(parse_with_allowed_formats("29.08.2023", &[DateOptions::auto()]), expected);
+  }
+
+  #[test]
+  fn test_parse_expect_weekday() {
+      assert_eq!(parse_expect_weekday("2023-09-01", Weekday::Fri, None), NaiveDate::from_ymd_opt(2023, 9, 1));
+      assert_eq!(parse_expect_weekday("01/09/2023 17:00", Weekday::Fri, Some(DateOptions::dmy('/'))), NaiveDate::from_ymd_opt(2023, 9, 1));
+      // a Thursday
+      assert_eq!(parse_expect_weekday("2023-08-31", Weekday::Fri, None), None);
+      assert_eq!(parse_expect_weekday("not a date", Weekday::Fri, None), None);
+  }
+
+  #[test]
+  fn test_parse_with_granularity() {
+    let granularity = |dt: &str| parse_with_granularity(dt, None).map(|(_, granularity)| granularity);
+    assert_eq!(granularity("1990s"), Some(Granularity::Decade));
+    assert_eq!(granularity("2023"), Some(Granularity::Year));
+    assert_eq!(granularity("2023-08"), Some(Granularity::Month));
+    assert_eq!(granularity("August 2023"), Some(Granularity::Month));
+    assert_eq!(granularity("29/08/2023"), Some(Granularity::Day));
+    assert_eq!(granularity("2023-08-29 19"), Some(Granularity::Hour));
+    assert_eq!(granularity("2023-08-29T19:34Z"), Some(Granularity::Minute));
+    assert_eq!(granularity("Aug 29, 2023 7:34:39 PM"), Some(Granularity::Second));
+    assert_eq!(granularity("2023-08-29 19:34:39.678+02"), Some(Granularity::Subsecond));
+    assert_eq!(granularity("not a date"), None);
+    let (datetime, _) = parse_with_granularity("2023-08", None).unwrap();
+    assert_eq!(datetime, NaiveDate::from_ymd_opt(2023, 8, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    // the date-time matches fuzzy_to_datetime
+    for dt in ["2023-08-29 21:34:39.678901+02:00", "29/08/2023 7:34 PM"] {
+      assert_eq!(parse_with_granularity(dt, None).map(|(datetime, _)| datetime), fuzzy_to_datetime(dt, None, None).ok());
+    }
+    let adjusted = DateOptions::auto().with_adjustments(true);
+    assert_eq!(parse_with_granularity("2023-08-29T00:00:00Z+25h", Some(adjusted)), Some((NaiveDate::from_ymd_opt(2023, 8, 30).unwrap().and_hms_opt(1, 0, 0).unwrap(), Granularity::Second)));
+  }
+
+  #[test]
+  fn test_fuzzy_decade_to_date() {
+    let (date, granularity) = fuzzy_decade_to_date("1990s").unwrap();
+    assert_eq!(date.to_string(), "1990-01-01");
+    assert_eq!(granularity, Granularity::Decade);
+    assert!(fuzzy_decade_to_date("the 2020's").is_none());
+    assert!(fuzzy_decade_to_date("1990").is_none());
+  }
+
+  #[test]
+  fn test_ordinal_days() {
+    assert_eq!(fuzzy_to_date_string("August 29th, 2023", None), Some("2023-08-29".to_string()));
+    assert_eq!(fuzzy_to_date_string("1ˢᵗ August 2023", None), Some("2023-08-01".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("22ⁿᵈ Aug 2023 19:34", None, None), Some("2023-08-22T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_named_month_dates() {
+    assert_eq!(fuzzy_to_date_string("August 29, 2023", None), Some("2023-08-29".to_string()));
+    assert_eq!(fuzzy_to_date_string("29 August 2023", None), Some("2023-08-29".to_string()));
+    assert_eq!(
+      fuzzy_to_datetime_string("Aug 29, 2023 19:34:39.678", None, None),
+      Some("2023-08-29T19:34:39.678Z".to_string())
+    );
+    // with a 2-digit year, a comma after the number following the month marks it as the day
+    assert_eq!(fuzzy_to_date_string("Aug 12, 05", Some(DateOptions::default())), Some("2005-08-12".to_string()));
+    // without the comma, the declared year-first order applies
+    assert_eq!(fuzzy_to_date_string("Aug 12 05", Some(DateOptions::default())), Some("2012-08-05".to_string()));
+    // and otherwise the year comes last
+    assert_eq!(fuzzy_to_date_string("5 Aug 12", None), Some("2012-08-05".to_string()));
+  }
+
+  #[test]
+  fn test_scrambled_named_month_dates() {
+    // the named month, ordinal day and year are assigned by type in any order
+    for sample in ["2023 29th Aug", "Aug 2023 29th", "29th 2023 Aug", "2023 Aug 29th", "Aug 29th 2023"] {
+      assert_eq!(fuzzy_to_date_string(sample, None), Some("2023-08-29".to_string()), "parsing {:?}", sample);
+    }
+    // an ordinal day wins over the usual year-last rule for 2-digit years
+    assert_eq!(fuzzy_to_date_string("23 Aug 29th", None), Some("2023-08-29".to_string()));
+  }
+
+  #[test]
+  fn test_period_anchor() {
+    let end_opts = DateOptions::auto().with_period_anchor(PeriodAnchor::End);
+    assert_eq!(fuzzy_to_date_string("2023-08", Some(end_opts.clone())), Some("2023-08-31".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023-Q3", Some(end_opts.clone())), Some("2023-09-30".to_string()));
+    assert_eq!(fuzzy_to_date_string("Q1 2024", Some(end_opts.clone())), Some("2024-03-31".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023", Some(end_opts)), Some("2023-12-31".to_string()));
+    // the start of the period is the default
+    assert_eq!(fuzzy_to_date_string("2023-Q3", None), Some("2023-07-01".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023-08", None), Some("2023-08-01".to_string()));
+  }
+
+  #[test]
+  fn test_require_t_separator() {
+    let strict = DateOptions::default().with_require_t_separator(true);
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39", Some(strict.clone()), None), Some("2023-08-29T19:34:39.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39", Some(strict.clone()), None), None);
+    assert!(fuzzy_to_datetime("2023-08-29 19:34:39", Some(strict.clone()), None).is_err());
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29", Some(strict), None), Some("2023-08-29T00:00:00.000Z".to_string()));
+    // lenient by default
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39", Some(DateOptions::default()), None), Some("2023-08-29T19:34:39.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_consistent_splitter() {
+    let strict = DateOptions::auto().with_consistent_splitter(true);
+    assert_eq!(fuzzy_to_date_string("2023-08/29", Some(strict.clone())), None);
+    assert_eq!(fuzzy_to_date_string("2023-08/29", Some(DateOptions::ymd('-').with_consistent_splitter(true))), None);
+    assert_eq!(fuzzy_to_date_string("2023-08-29", Some(strict.clone())), Some("2023-08-29".to_string()));
+    // dots in the time don't count
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19.34.39", Some(strict), None), Some("2023-08-29T19:34:39.000Z".to_string()));
+    // lenient by default
+    assert!(fuzzy_to_date_string("2023-08/29", None).is_some());
+  }
+
+  #[test]
+  fn test_subsecond_digits() {
+    let nanos = DateOptions::auto().with_subsecond_digits(9);
+    let input = "2023-08-29T19:34:39.678901234Z";
+    assert_eq!(fuzzy_to_datetime_string(input, Some(nanos.clone()), None), Some(input.to_string()));
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_nano_opt(19, 34, 39, 678_901_234).unwrap();
+    assert_eq!(fuzzy_to_datetime(input, Some(nanos.clone()), None), Ok(expected));
+    let micros = DateOptions::auto().with_subsecond_digits(6);
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19:34:39.678901+02:00", Some(micros.clone()), None), Some("2023-08-29T19:34:39.678901+02:00".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.5", Some(micros), None), Some("2023-08-29T19:34:39.500000Z".to_string()));
+    // milliseconds by default
+    assert_eq!(fuzzy_to_datetime_string(input, None, None), Some("2023-08-29T19:34:39.678Z".to_string()));
+    assert_eq!(fuzzy_to_datetime(input, None, None).map(|dt| dt.nanosecond()), Ok(678_000_000));
+  }
+
+  #[test]
+  fn test_time_with_leading_designator() {
+    assert_eq!(fuzzy_to_time("T19:34:39"), NaiveTime::from_hms_opt(19, 34, 39));
+    assert_eq!(fuzzy_to_time("T1934"), NaiveTime::from_hms_opt(19, 34, 0));
+    assert_eq!(fuzzy_to_time(" t19:34:39.678Z"), NaiveTime::from_hms_milli_opt(19, 34, 39, 678));
+    assert_eq!(fuzzy_to_time("T"), None);
+    assert_eq!(fuzzy_to_time("Tea"), None);
+  }
+
+  #[test]
+  fn test_two_digit_year_pivot() {
+    assert_eq!(fuzzy_to_date_string("28/02/98", None), Some("1998-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("12/25/21", Some(DateOptions::new(DateOrder::MDY, Some('/')))), Some("2021-12-25".to_string()));
+    // the default pivot is 69
+    assert_eq!(DateOptions::default().pivot(), Some(69));
+    assert_eq!(fuzzy_to_date_string("28/02/68", None), Some("2068-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28/02/69", None), Some("1969-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28/02/68", Some(DateOptions::dmy('/'))), Some("2068-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28 Feb 68", None), Some("2068-02-28".to_string()));
+    let pivot = DateOptions::dmy('/').with_pivot(50);
+    assert_eq!(fuzzy_to_date_string("28/02/49", Some(pivot.clone())), Some("2049-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28/02/50", Some(pivot.clone())), Some("1950-02-28".to_string()));
+    // fixed-width and named-month dates honour the pivot too
+    let compact = DateOptions::new(DateOrder::DMY, None);
+    assert_eq!(fuzzy_to_date_string("280268", Some(compact.clone())), Some("2068-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("280298", Some(compact)), Some("1998-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28 Feb 50", Some(DateOptions::auto().with_pivot(51))), Some("2050-02-28".to_string()));
+    // 4-digit years are unaffected
+    assert_eq!(fuzzy_to_date_string("28/02/1968", Some(pivot)), Some("1968-02-28".to_string()));
+    // or relative to today
+    let sliding = DateOptions::dmy('/').with_sliding_window();
+    assert_eq!(sliding.pivot(), None);
+    assert_eq!(fuzzy_to_date_string("28/02/10", Some(sliding)), Some("2010-02-28".to_string()));
+  }
+
+  #[test]
+  fn test_normalize_to_utc() {
+    let opts = DateOptions::auto().with_normalize_to_utc(true);
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39+05:30", Some(opts.clone()), None), Some("2023-08-29T14:04:39.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39.678-08:00", Some(opts.clone()), None), Some("2023-08-30T03:34:39.678Z".to_string()));
+    // the date rolls back over midnight
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T02:15:00+05:30", Some(opts.clone()), None), Some("2023-08-28T20:45:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("2024-01-01T00:30:00+01:00", Some(opts.clone()), None), Some("2023-12-31T23:30:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39Z", Some(opts), None), Some("2023-08-29T19:34:39.000Z".to_string()));
+    // the offset is preserved by default
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39+05:30", None, None), Some("2023-08-29T19:34:39.000+05:30".to_string()));
+  }
+
+  #[test]
+  fn test_max_input_len() {
+    let long_input = format!("2023-08-29 {}", "9".repeat(10_000));
+    let started = std::time::Instant::now();
+    assert_eq!(fuzzy_to_datetime_string(&long_input, None, None), None);
+    assert_eq!(try_fuzzy_to_date_string(&long_input, None), Err(FuzzyDateError::TooLong(64)));
+    assert!(started.elapsed() < std::time::Duration::from_millis(100));
+    // longer inputs are accepted if the limit is raised
+    let padded = format!("2023-08-29{}", " ".repeat(100));
+    assert_eq!(fuzzy_to_date_string(&padded, None), None);
+    assert_eq!(fuzzy_to_date_string(&padded, Some(DateOptions::auto().with_max_input_len(128))), Some("2023-08-29".to_string()));
+    assert_eq!(normalize_date_string(&padded, None), None);
+    // canonical ISO input is held to the same limit
+    let short = DateOptions::auto().with_max_input_len(16);
+    assert!(fuzzy_to_datetime("2023-08-29T19:34:39", Some(short.clone()), None).is_err());
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T19:34:39", Some(short.clone()), None), None);
+    assert_eq!(normalize_datetime_string("2023-08-29T19:34:39.000Z", Some(short)), None);
+  }
+
+  #[test]
+  fn test_try_variants() {
+    assert_eq!(try_fuzzy_to_date_string("2023-13-40", None), Err(FuzzyDateError::MonthOutOfRange(13)));
+    assert_eq!(try_fuzzy_to_date_string("2023-08-40", None), Err(FuzzyDateError::DayOutOfRange(40)));
+    assert_eq!(try_fuzzy_to_date_string("203-08-29", None), Err(FuzzyDateError::YearTooSmall(203)));
+    assert_eq!(try_fuzzy_to_date_string(" ", None), Err(FuzzyDateError::EmptyInput));
+    assert_eq!(try_fuzzy_to_date_string("apple", None), Err(FuzzyDateError::NonDateText("apple".to_string())));
+    assert_eq!(try_fuzzy_to_date_string("29/08/2023", None), Ok("2023-08-29".to_string()));
+    assert_eq!(try_fuzzy_to_datetime_string("2023-08-29 25:00", None, None), Err(FuzzyDateError::HourOutOfRange(25)));
+    assert_eq!(try_fuzzy_to_datetime_string("2023-08-29 19:34", None, None), Ok("2023-08-29T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_two_digit_year_window() {
+    let opts = DateOptions::dmy('/').with_reference_year(2023, 50);
+    assert_eq!(fuzzy_to_date_string("28/02/95", Some(opts.clone())), Some("1995-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28/02/10", Some(opts.clone())), Some("2010-02-28".to_string()));
+    assert_eq!(fuzzy_to_date_string("28 Feb 73", Some(opts.clone())), Some("2073-02-28".to_string()));
+    assert_eq!(opts.reference_year(), Some(2023));
+    assert_eq!(opts.pivot(), None);
+    // only the last rule set applies
+    assert_eq!(fuzzy_to_date_string("28/02/95", Some(opts.with_pivot(96))), Some("2095-02-28".to_string()));
+  }
+
+  #[test]
+  fn test_month_name_dates() {
+    let samples = [
+      ("15 Jan 2023", "2023-01-15"),
+      ("Jan 15, 2023", "2023-01-15"),
+      ("January 15, 2023", "2023-01-15"),
+      ("15-January-2023", "2023-01-15"),
+      ("15/Jan/2023", "2023-01-15"),
+      ("2023 Feb 3", "2023-02-03"),
+      ("2023-Feb-03", "2023-02-03"),
+    ];
+    for (input, expected) in samples {
+      assert_eq!(fuzzy_to_date_string(input, None), Some(expected.to_string()), "parsing {:?}", input);
+    }
+    assert_eq!(fuzzy_to_datetime_string("15-Jan-2023 19:34", None, None), Some("2023-01-15T19:34:00.000Z".to_string()));
+    // two short numbers follow the date order, with the year last unless it comes first
+    assert_eq!(fuzzy_to_date_string("03 Feb 04", None), Some("2004-02-03".to_string()));
+    assert_eq!(fuzzy_to_date_string("03 Feb 04", Some(DateOptions::new(DateOrder::YMD, Some('-')))), Some("2003-02-04".to_string()));
+  }
+
+  #[test]
+  fn test_unit_marked_dates() {
+    assert_eq!(fuzzy_to_date_string("2023 year 8 month 29 day", None), Some("2023-08-29".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023年8月29日", None), Some("2023-08-29".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("2023 year 8 month 29 day 19:34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023 year 13 month 29 day", None), None);
+  }
+
+  #[test]
+  fn test_german_and_french_dates() {
+    let samples = [
+      ("29. August 2023", "2023-08-29"),
+      ("den 29. August 2023", "2023-08-29"),
+      ("Den 1. März 2023", "2023-03-01"),
+      ("der 24. Dezember 2023", "2023-12-24"),
+      ("le 29 août 2023", "2023-08-29"),
+      ("Le 1er mars 2023", "2023-03-01"),
+      ("29 juillet 2023", "2023-07-29"),
+    ];
+    for (input, expected) in samples {
+      assert_eq!(fuzzy_to_date_string(input, None), Some(expected.to_string()), "parsing {:?}", input);
+    }
+    assert_eq!(fuzzy_to_datetime_string("den 29. August 2023 19:34", None, None), Some("2023-08-29T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_month_aliases() {
+    assert_eq!(fuzzy_to_date_string("5 Sept 2023", None), Some("2023-09-05".to_string()));
+    assert_eq!(fuzzy_to_date_string("Sep. 5, 2023", None), Some("2023-09-05".to_string()));
+    assert_eq!(fuzzy_to_date_string("Janurary 5, 2023", None), None);
+    let opts = DateOptions::auto().with_month_aliases(&[("Janurary", 1), ("Setembro", 9)]);
+    assert_eq!(fuzzy_to_date_string("Janurary 5, 2023", Some(opts.clone())), Some("2023-01-05".to_string()));
+    assert_eq!(fuzzy_to_date_string("5 SETEMBRO. 2023 19:34", Some(opts.clone())), Some("2023-09-05".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("5 setembro 2023 19:34", Some(opts), None), Some("2023-09-05T19:34:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_mixed_time_separators() {
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34.39", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19.34:39", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+    assert_eq!(fuzzy_to_time("19:34.39"), NaiveTime::from_hms_opt(19, 34, 39));
+    // other fractions after the minutes are still fractional minutes
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34.75", None, None), Some("2023-08-29T19:34:45.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_parse_or_default() {
+    let default_date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    assert_eq!(fuzzy_to_date_or("not a date", default_date, None), default_date);
+    assert_eq!(fuzzy_to_date_or("2023-13-01", default_date, None), default_date);
+    assert_eq!(fuzzy_to_date_or("29/08/2023", default_date, None), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap());
+    let default_datetime = default_date.and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(fuzzy_to_datetime_or("garbage", default_datetime, None), default_datetime);
+    assert_eq!(fuzzy_to_datetime_or("2023-08-29 19:34", default_datetime, None), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(19, 34, 0).unwrap());
+  }
+
+  #[test]
+  fn test_spaced_utc_offset() {
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39 +05:30", None, None), Some("2023-08-29T19:34:39.000+05:30".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19:34:39.678 -08", None, None), Some("2023-08-29T19:34:39.678-08:00".to_string()));
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(14, 4, 39).unwrap();
+    assert_eq!(fuzzy_to_datetime("2023-08-29 19:34:39 +05:30", None, None), Ok(expected));
+    // a token with non-ASCII characters is not an offset
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 19:34:39 +1é1", None, None), Some("2023-08-29T19:34:39.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_fuzzy_to_datetime_nanos() {
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_nano_opt(19, 34, 39, 123_456_789);
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29T19:34:39.123456789Z", None), expected);
+    assert_eq!(fuzzy_to_datetime_nanos("29/08/2023 19:34:39.123456789", None), expected);
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 21:34:39.123456789+02:00", None), expected);
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 19:34:39.5", None), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_milli_opt(19, 34, 39, 500));
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29", None), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 25:00", None), None);
+    // as with fuzzy_to_datetime, options apply once to the full-precision value
+    let adjusted = DateOptions::auto().with_adjustments(true);
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 21:04:39.123456789-90m", Some(adjusted)), expected);
+    let lenient = DateOptions::auto().with_lenient_time(true);
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 25:00", Some(lenient)), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0));
+    let range = DateOptions::auto().with_valid_range(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2099, 12, 31).unwrap());
+    assert_eq!(fuzzy_to_datetime_nanos("2023-08-29 19:34:39.123456789", Some(range.clone())), expected);
+    assert_eq!(fuzzy_to_datetime_nanos("1993-08-29 19:34:39.123456789", Some(range.clone())), None);
+    assert_eq!(fuzzy_to_datetime_nanos("1990s", Some(range)), None);
+    let sentinels = DateOptions::auto().with_reject_sentinels(true);
+    assert_eq!(fuzzy_to_datetime_nanos("9999-12-31 23:59:59.999999999", Some(sentinels)), None);
+    // fuzzy_to_datetime still truncates to milliseconds
+    assert_eq!(fuzzy_to_datetime("2023-08-29T19:34:39.123456789Z", None, None).map(|dt| dt.nanosecond()), Ok(123_000_000));
+  }
+
+  #[test]
+  fn test_compact_dates_follow_the_dataset_order() {
+    // 8 digits are ambiguous without a separator, so the detected order decides
+    assert_eq!(fuzzy_to_date_string("08071998", Some(DateOptions::mdy_fixed())), Some("1998-08-07".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("08071998 193439", Some(DateOptions::mdy_fixed()), None), Some("1998-08-07T19:34:39.000Z".to_string()));
+    let detected = detect_date_format_from_list(&["12/25/1998", "01/02/1999"]);
+    let compact = DateOptions::new(detected.order(), None);
+    assert_eq!(fuzzy_to_date_string("08071998", Some(compact)), Some("1998-08-07".to_string()));
+    // a year first is never read in another order
+    assert_eq!(fuzzy_to_date_string("19980807", Some(DateOptions::mdy_fixed())), None);
+  }
+
+  #[test]
+  fn test_guess_fallback() {
+    let opts = DateOptions::default();
+    assert_eq!(fuzzy_to_date_string("29-08-2023", Some(opts.clone())), None);
+    let hinted = opts.with_guess_fallback(true);
+    assert_eq!(fuzzy_to_date_string("29-08-2023", Some(hinted.clone())), Some("2023-08-29".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19:34", Some(hinted.clone()), None), Some("2023-08-29T19:34:00.000Z".to_string()));
+    // the declared order still wins where it works
+    assert_eq!(fuzzy_to_date_string("2023-08-05", Some(hinted.clone())), Some("2023-08-05".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023-13-45", Some(hinted)), None);
+  }
+
+  #[test]
+  fn test_two_part_dates() {
+    // the 4-digit year sets the order of a month and year, whatever the date order
+    assert_eq!(fuzzy_to_date_string("08-2023", None), Some("2023-08-01".to_string()));
+    assert_eq!(fuzzy_to_date_string("2023-08", None), Some("2023-08-01".to_string()));
+    assert_eq!(fuzzy_to_date_string("8/2023", Some(DateOptions::dmy('/'))), Some("2023-08-01".to_string()));
+    assert_eq!(fuzzy_to_date_string("08-2023", Some(DateOptions::default().with_period_anchor(PeriodAnchor::End))), Some("2023-08-31".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("08-2023 19:34", None, None), Some("2023-08-01T19:34:00.000Z".to_string()));
+    // two short numbers need an explicit reading
+    assert_eq!(fuzzy_to_date_string("08/23", Some(DateOptions::dmy('/'))), None);
+    let month_year = DateOptions::dmy('/').with_two_part_dates(TwoPartDates::MonthYear);
+    assert_eq!(fuzzy_to_date_string("08/23", Some(month_year)), Some("2023-08-01".to_string()));
+    let day_month = DateOptions::dmy('/').with_two_part_dates(TwoPartDates::DayMonth(2024));
+    assert_eq!(fuzzy_to_date_string("29/08", Some(day_month.clone())), Some("2024-08-29".to_string()));
+    assert_eq!(fuzzy_to_date_string("29/08/2023", Some(day_month)), Some("2023-08-29".to_string()));
+    let month_day = DateOptions::mdy('/').with_two_part_dates(TwoPartDates::DayMonth(2024));
+    assert_eq!(fuzzy_to_date_string("08/29", Some(month_day)), Some("2024-08-29".to_string()));
+  }
+
+  #[test]
+  fn test_compact_order_fallback() {
+    let mdy = DateOptions::new(DateOrder::MDY, None);
+    assert_eq!(fuzzy_to_date_string("08071998", Some(mdy.clone())), Some("1998-08-07".to_string()));
+    assert_eq!(fuzzy_to_date_string("08071998", Some(DateOptions::dmy_fixed())), Some("1998-07-08".to_string()));
+    assert_eq!(fuzzy_to_date_string("13071998", Some(mdy.clone())), None);
+    // 13 can't be a month, so the day comes first
+    let opts = mdy.with_compact_order_fallback(true);
+    assert_eq!(fuzzy_to_date_string("13071998", Some(opts.clone())), Some("1998-07-13".to_string()));
+    assert_eq!(fuzzy_to_date_string("08071998", Some(opts.clone())), Some("1998-08-07".to_string()));
+    assert_eq!(fuzzy_to_date_string("99999999", Some(opts)), None);
+  }
+
+  #[test]
+  fn test_lenient_time() {
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 25:99", None, None), None);
+    let opts = DateOptions::auto().with_lenient_time(true);
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29 25:99", Some(opts.clone()), None), Some("2023-08-29T00:00:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19:34", Some(opts.clone()), None), Some("2023-08-29T19:34:00.000Z".to_string()));
+    // the date must still be valid
+    assert_eq!(fuzzy_to_datetime_string("2023-13-29 25:99", Some(opts), None), None);
+  }
+
+  #[test]
+  fn test_adjustments() {
+    let opts = DateOptions::auto().with_adjustments(true);
+    assert_eq!(fuzzy_to_datetime_string("2023-08-29T00:00:00Z+25h", Some(opts.clone()), None), Some("2023-08-30T01:00:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("29/08/2023 19:34:39.678-90m", Some(opts.clone()), None), Some("2023-08-29T18:04:39.678Z".to_string()));
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 31).unwrap().and_hms_opt(19, 34, 39).unwrap();
+    assert_eq!(fuzzy_to_datetime("2023-08-29 19:34:39+2d", Some(opts.clone()), None), Ok(expected));
+    assert_eq!(fuzzy_to_date_string("2023-08-29+é", Some(opts)), None);
+    // only applied if enabled
+    assert_ne!(fuzzy_to_datetime_string("2023-08-29T00:00:00Z+25h", None, None), Some("2023-08-30T01:00:00.000Z".to_string()));
+  }
+
+  #[test]
+  fn test_reject_sentinels() {
+    // parsed as a literal date by default
+    assert_eq!(fuzzy_to_date_string("9999-12-31", None), Some("9999-12-31".to_string()));
+    let opts = DateOptions::auto().with_reject_sentinels(true);
+    assert_eq!(fuzzy_to_date_string("9999-12-31", Some(opts.clone())), None);
+    assert!(fuzzy_to_datetime("9999-12-31T00:00:00Z", Some(opts.clone()), None).is_err());
+    assert_eq!(fuzzy_to_date_string("31/12/9999", Some(opts.clone())), None);
+    assert_eq!(explain_failure("9999-12-31", Some(opts.clone())).as_deref(), Some("9999-12-31 is a sentinel rather than a real date"));
+    assert_eq!(fuzzy_to_date_string("2023-12-31", Some(opts)), Some("2023-12-31".to_string()));
+  }
+
+  #[test]
+  fn test_valid_range() {
+    let start = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2020, 12, 31).unwrap();
+    let opts = DateOptions::auto().with_valid_range(start, end);
+    assert_eq!(fuzzy_to_date_string("1985-06-14", Some(opts.clone())), Some("1985-06-14".to_string()));
+    // too late and too early for a past-only window
+    assert_eq!(fuzzy_to_date_string("2035-06-14", Some(opts.clone())), None);
+    assert_eq!(fuzzy_to_datetime_string("1850-06-14 10:00", Some(opts.clone()), None), None);
+    assert!(fuzzy_to_datetime("2035-06-14", Some(opts), None).is_err());
+  }
+
   #[test]
   fn test_fuzzy_to_date_string() {
     // correct date
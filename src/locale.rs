@@ -0,0 +1,87 @@
+use chrono::Weekday;
+use crate::{Clock, DateOptions, NumberLocale};
+
+/// Presets bundling the date order, splitter, clock, number locale, month and weekday names
+/// and filler words of common locales, e.g. Locale::German.date_options() for "Dienstag, 29. August 2023 um 19:34 Uhr"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+  /// Month-first dates such as 08/29/2023 with AM/PM times
+  UsEnglish,
+  /// Day-first dates such as 29/08/2023
+  UkEnglish,
+  /// Day-first dates such as 29.08.2023 on a 24-hour clock with decimal commas
+  German,
+  /// Day-first dates such as 29/08/2023 on a 24-hour clock with decimal commas
+  French,
+}
+
+const GERMAN_MONTHS: [(&str, u8); 17] = [
+  ("januar", 1), ("jänner", 1), ("februar", 2), ("märz", 3), ("mär", 3), ("mai", 5), ("juni", 6), ("juli", 7),
+  ("oktober", 10), ("okt", 10), ("dezember", 12), ("dez", 12), ("jan", 1), ("feb", 2), ("apr", 4), ("aug", 8), ("nov", 11),
+];
+
+const GERMAN_WEEKDAYS: [(&str, Weekday); 8] = [
+  ("montag", Weekday::Mon), ("dienstag", Weekday::Tue), ("mittwoch", Weekday::Wed), ("donnerstag", Weekday::Thu),
+  ("freitag", Weekday::Fri), ("samstag", Weekday::Sat), ("sonnabend", Weekday::Sat), ("sonntag", Weekday::Sun),
+];
+
+const FRENCH_MONTHS: [(&str, u8); 20] = [
+  ("janvier", 1), ("janv", 1), ("février", 2), ("fevrier", 2), ("févr", 2), ("mars", 3), ("avril", 4), ("avr", 4),
+  ("mai", 5), ("juin", 6), ("juillet", 7), ("juil", 7), ("août", 8), ("aout", 8), ("septembre", 9), ("sept", 9),
+  ("octobre", 10), ("novembre", 11), ("décembre", 12), ("déc", 12),
+];
+
+const FRENCH_WEEKDAYS: [(&str, Weekday); 7] = [
+  ("lundi", Weekday::Mon), ("mardi", Weekday::Tue), ("mercredi", Weekday::Wed), ("jeudi", Weekday::Thu),
+  ("vendredi", Weekday::Fri), ("samedi", Weekday::Sat), ("dimanche", Weekday::Sun),
+];
+
+impl Locale {
+  /// Options configured for the locale, which may be refined further with the usual builder methods
+  pub fn date_options(self) -> DateOptions {
+    match self {
+      Locale::UsEnglish => DateOptions::mdy('/').with_number_locale(NumberLocale::DecimalPoint),
+      Locale::UkEnglish => DateOptions::dmy('/').with_number_locale(NumberLocale::DecimalPoint),
+      Locale::German => DateOptions::dmy('.')
+        .with_clock(Clock::Clock24)
+        .with_number_locale(NumberLocale::DecimalComma)
+        .with_month_aliases(&GERMAN_MONTHS)
+        .with_weekday_aliases(&GERMAN_WEEKDAYS)
+        .with_filler_words(&["am", "um", "uhr"]),
+      Locale::French => DateOptions::dmy('/')
+        .with_clock(Clock::Clock24)
+        .with_number_locale(NumberLocale::DecimalComma)
+        .with_month_aliases(&FRENCH_MONTHS)
+        .with_weekday_aliases(&FRENCH_WEEKDAYS)
+        .with_filler_words(&["à"]),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{fuzzy_to_date_string, fuzzy_to_datetime_string};
+
+  #[test]
+  fn test_german_locale() {
+    let opts = Locale::German.date_options();
+    assert_eq!(fuzzy_to_datetime_string("Dienstag, 29. August 2023 um 19:34 Uhr", Some(opts.clone()), None), Some("2023-08-29T19:34:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("am Dienstag, den 29. Aug. 2023", Some(opts.clone()), None), Some("2023-08-29T00:00:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("29.08.2023 19:34:39,678", Some(opts.clone()), None), Some("2023-08-29T19:34:39.678Z".to_string()));
+    assert_eq!(fuzzy_to_date_string("3. Okt. 2023", Some(opts.clone())), Some("2023-10-03".to_string()));
+    // the weekday is checked against the date as usual
+    assert_eq!(fuzzy_to_date_string("Montag, 29. August 2023", Some(opts.with_validate_weekday(true))), None);
+  }
+
+  #[test]
+  fn test_french_and_english_locales() {
+    let french = Locale::French.date_options();
+    assert_eq!(fuzzy_to_datetime_string("mardi 29 août 2023 à 19:34", Some(french.clone()), None), Some("2023-08-29T19:34:00.000Z".to_string()));
+    assert_eq!(fuzzy_to_date_string("le 1er juil. 2023", Some(french.clone())), Some("2023-07-01".to_string()));
+    assert_eq!(fuzzy_to_date_string("05/06/2023", Some(french)), Some("2023-06-05".to_string()));
+    assert_eq!(fuzzy_to_date_string("05/06/2023", Some(Locale::UsEnglish.date_options())), Some("2023-05-06".to_string()));
+    assert_eq!(fuzzy_to_date_string("05/06/2023", Some(Locale::UkEnglish.date_options())), Some("2023-06-05".to_string()));
+    assert_eq!(fuzzy_to_datetime_string("Tuesday, August 29, 2023 7:34 pm", Some(Locale::UsEnglish.date_options()), None), Some("2023-08-29T19:34:00.000Z".to_string()));
+  }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+use chrono::NaiveDate;
+use simple_string_patterns::CharGroupMatch;
+
+/// A recurring annual date without a year, such as the ISO 8601 "--08-29" for 29 August every year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonthDay(pub u8, pub u8);
+
+impl MonthDay {
+  pub fn month(&self) -> u8 {
+    self.0
+  }
+
+  pub fn day(&self) -> u8 {
+    self.1
+  }
+
+  /// the date in a given year, or None if the day does not occur that year, e.g. 29 February
+  pub fn with_year(&self, year: i32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, self.0 as u32, self.1 as u32)
+  }
+}
+
+/// render as an ISO 8601 recurring date, e.g. --08-29
+impl fmt::Display for MonthDay {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "--{:02}-{:02}", self.0, self.1)
+  }
+}
+
+/// Parse an ISO 8601 recurring annual date in extended or basic notation, e.g. "--08-29" or "--0829"
+/// The day must exist in some year, so "--02-29" is valid but "--02-30" is not
+pub fn fuzzy_to_month_day(dt: &str) -> Option<MonthDay> {
+  let rest = dt.trim().strip_prefix("--")?;
+  let (month_str, day_str) = match rest.split_once('-') {
+    Some(parts) => parts,
+    None if rest.len() == 4 && rest.bytes().all(|b| b.is_ascii_digit()) => rest.split_at(2),
+    None => return None,
+  };
+  if month_str.len() != 2 || day_str.len() != 2 || !month_str.is_digits_only() || !day_str.is_digits_only() {
+    return None;
+  }
+  let month_day = MonthDay(month_str.parse().ok()?, day_str.parse().ok()?);
+  // 2000 is a leap year
+  month_day.with_year(2000).map(|_| month_day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fuzzy_to_month_day() {
+    assert_eq!(fuzzy_to_month_day("--08-29"), Some(MonthDay(8, 29)));
+    assert_eq!(fuzzy_to_month_day("--0829"), Some(MonthDay(8, 29)));
+    assert_eq!(fuzzy_to_month_day("--08-29").and_then(|md| md.with_year(2023)), NaiveDate::from_ymd_opt(2023, 8, 29));
+    assert_eq!(fuzzy_to_month_day("--02-29").map(|md| md.to_string()), Some("--02-29".to_string()));
+    assert_eq!(fuzzy_to_month_day("--02-29").and_then(|md| md.with_year(2023)), None);
+    assert_eq!(fuzzy_to_month_day("--02-30"), None);
+    assert_eq!(fuzzy_to_month_day("2023-08-29"), None);
+    assert_eq!(fuzzy_to_month_day("--'月"), None);
+  }
+}
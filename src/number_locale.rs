@@ -0,0 +1,21 @@
+/// Which character separates a fraction of a second from the whole seconds, e.g. in 19:34:39.678 or 19:34:39,678
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberLocale {
+  /// Accept either a decimal point or a decimal comma
+  #[default]
+  Any,
+  /// Only a decimal point, as in English-speaking locales
+  DecimalPoint,
+  /// Only a decimal comma, as in most of continental Europe
+  DecimalComma,
+}
+
+impl NumberLocale {
+  pub(crate) fn fraction_separators(&self) -> &'static [char] {
+    match self {
+      Self::Any => &['.', ','],
+      Self::DecimalPoint => &['.'],
+      Self::DecimalComma => &[','],
+    }
+  }
+}
@@ -0,0 +1,87 @@
+use simple_string_patterns::CharGroupMatch;
+
+/// Split a trailing UTC designator or numeric offset from a time or subsecond segment,
+/// e.g. "19:34:39+05:30" => ("19:34:39", "+05:30") or "678901+00" => ("678901", "+00")
+/// Offsets may be written as ±HH, ±HHMM or ±HH:MM. The zone is empty if none is found
+/// This must only be applied to time segments, as hyphenated dates also look like offsets
+pub(crate) fn split_zone_suffix(text: &str) -> (&str, &str) {
+  if let Some(rest) = text.strip_suffix(['Z', 'z']) {
+    return (rest, &text[rest.len()..]);
+  }
+  if let Some(idx) = text.rfind(['+', '-']) {
+    let (rest, zone) = text.split_at(idx);
+    if rest.ends_with(|c: char| c.is_ascii_digit()) && utc_offset_minutes(zone).is_some() {
+      return (rest, zone);
+    }
+  }
+  (text, "")
+}
+
+/// Convert a UTC designator or numeric offset to minutes east of UTC,
+/// e.g. "Z" => 0, "+05:30" => 330 and "-08" => -480
+pub(crate) fn utc_offset_minutes(zone: &str) -> Option<i32> {
+  if zone.eq_ignore_ascii_case("z") {
+    return Some(0);
+  }
+  let sign = match zone.chars().next()? {
+    '+' => 1,
+    '-' => -1,
+    _ => return None,
+  };
+  let digits = &zone[1..];
+  // byte lengths and offsets below assume ASCII
+  if !digits.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+    return None;
+  }
+  let (hrs, mins) = match digits.len() {
+    2 => (digits, "00"),
+    4 => digits.split_at(2),
+    5 if digits.as_bytes()[2] == b':' => (&digits[..2], &digits[3..]),
+    _ => return None,
+  };
+  if !hrs.is_digits_only() || !mins.is_digits_only() {
+    return None;
+  }
+  let (hrs, mins) = (hrs.parse::<i32>().ok()?, mins.parse::<i32>().ok()?);
+  if hrs > 23 || mins > 59 {
+    return None;
+  }
+  Some(sign * (hrs * 60 + mins))
+}
+
+/// Format an offset in minutes east of UTC as ±HH:MM
+pub(crate) fn format_utc_offset(minutes: i32) -> String {
+  let sign = if minutes < 0 { '-' } else { '+' };
+  let abs_minutes = minutes.abs();
+  format!("{}{:02}:{:02}", sign, abs_minutes / 60, abs_minutes % 60)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_zone_suffix() {
+    assert_eq!(split_zone_suffix("19:34:39+05:30"), ("19:34:39", "+05:30"));
+    assert_eq!(split_zone_suffix("678901+00"), ("678901", "+00"));
+    assert_eq!(split_zone_suffix("193439-0800"), ("193439", "-0800"));
+    assert_eq!(split_zone_suffix("678Z"), ("678", "Z"));
+    assert_eq!(split_zone_suffix("19:34:39"), ("19:34:39", ""));
+    // not an offset
+    assert_eq!(split_zone_suffix("19:34+5"), ("19:34+5", ""));
+  }
+
+  #[test]
+  fn test_utc_offset_minutes() {
+    assert_eq!(utc_offset_minutes("Z"), Some(0));
+    assert_eq!(utc_offset_minutes("+00"), Some(0));
+    assert_eq!(utc_offset_minutes("+05:30"), Some(330));
+    assert_eq!(utc_offset_minutes("-0800"), Some(-480));
+    assert_eq!(utc_offset_minutes("+24:00"), None);
+    assert_eq!(utc_offset_minutes("05:30"), None);
+    assert_eq!(utc_offset_minutes("+1é1"), None);
+    assert_eq!(utc_offset_minutes("+é:11"), None);
+    assert_eq!(format_utc_offset(330), "+05:30");
+    assert_eq!(format_utc_offset(-480), "-08:00");
+  }
+}
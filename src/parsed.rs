@@ -0,0 +1,125 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+use simple_string_patterns::{CharGroupMatch, CharType, SimplContainsType};
+use crate::{
+  converters::{format_subseconds, fuzzy_to_formatted_time_parts, split_parenthetical_suffix, split_trailing_adjustment, subsecond_digits_to_nanos},
+  granularity::{date_granularity, time_granularity},
+  guess::{guess_date_order, DateOrderGuess},
+  offset::{format_utc_offset, utc_offset_minutes},
+  fuzzy_decade_to_date, fuzzy_to_date_string_with_optional_time, split_datetime_segments, DateOptions, DateOrder, Granularity,
+};
+
+/// The components of a parsed date or date-time string with how it was read,
+/// built from the normalised output of the string parser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedDateTime {
+  pub date: NaiveDate,
+  /// The local time with up to nanosecond precision, or None if the source only has a date
+  pub time: Option<NaiveTime>,
+  /// The numeric UTC offset in minutes, e.g. 330 for +05:30. None for a UTC designator or no zone, both read as UTC
+  pub offset_minutes: Option<i32>,
+  /// The finest unit of time present in the source
+  pub granularity: Granularity,
+  /// The order a numeric date was read in, or None for dates with month names, where each part is identified by type
+  pub order: Option<DateOrder>,
+  /// The order was guessed, but the date could be day-first or month-first, e.g. 05/06/2023
+  pub ambiguous: bool,
+}
+
+impl ParsedDateTime {
+  /// Parse a date or date-time string as with fuzzy_to_datetime, keeping its components apart.
+  /// Decades such as "1990s" are recognised too
+  pub fn parse(dt: &str, date_opts: Option<DateOptions>) -> Option<Self> {
+    if let Some((date, granularity)) = fuzzy_decade_to_date(dt) {
+      return Some(ParsedDateTime { date, time: None, offset_minutes: None, granularity, order: None, ambiguous: false });
+    }
+    let opts = date_opts.unwrap_or_else(DateOptions::auto);
+    if opts.adjustments() {
+      if let Some((base, adjustment)) = split_trailing_adjustment(dt) {
+        let parsed = Self::parse(base, Some(opts))?;
+        let datetime = parsed.to_naive_datetime().checked_add_signed(adjustment)?;
+        return Some(ParsedDateTime { date: datetime.date(), time: Some(datetime.time()), offset_minutes: None, ..parsed });
+      }
+    }
+    let (formatted_date, time_part, milli_tz) = fuzzy_to_date_string_with_optional_time(dt, Some(opts.clone()))?;
+    let date = NaiveDate::parse_from_str(&formatted_date, "%Y-%m-%d").ok()?;
+    let (time, offset_minutes, granularity) = match time_part {
+      Some(time_str) => {
+        // an invalid time may be replaced with midnight if lenient_time is set, as in fuzzy_to_datetime_string
+        let (formatted_time, tz_suffix) = fuzzy_to_formatted_time_parts(&time_str, &milli_tz, None, true, '.', 9)
+          .or_else(|| (opts.lenient_time() && time_str.has_digits()).then(|| fuzzy_to_formatted_time_parts("00:00:00", "", None, true, '.', 9)).flatten())?;
+        // the suffix has a decimal point, 9 subsecond digits and the zone, e.g. ".678000000+05:30"
+        let (subseconds, zone) = tz_suffix.get(1..)?.split_at(9);
+        let time = NaiveTime::parse_from_str(&formatted_time, "%H:%M:%S").ok()?.with_nanosecond(subsecond_digits_to_nanos(subseconds))?;
+        let offset_minutes = Some(zone).filter(|zone| zone.starts_with(['+', '-'])).and_then(utc_offset_minutes);
+        (Some(time), offset_minutes, time_granularity(&time_str, milli_tz.starts_with(|c: char| c.is_ascii_digit())))
+      },
+      None => {
+        let date_str = split_parenthetical_suffix(dt).map_or(dt, |(base, _)| base);
+        (None, None, date_granularity(date_str, &opts))
+      },
+    };
+    let date_part = split_datetime_segments(dt).date;
+    let (order, ambiguous) = if date_part.contains_type(CharType::Alpha) {
+      (None, false)
+    } else {
+      let resolved = opts.resolve(date_part);
+      let ambiguous = opts.is_auto() && guess_date_order(date_part, resolved.splitter()) == DateOrderGuess::DayOrMonthFirst;
+      (Some(resolved.order()), ambiguous)
+    };
+    Some(ParsedDateTime { date, time, offset_minutes, granularity, order, ambiguous })
+  }
+
+  /// The date and time in UTC, at midnight for a date only
+  pub fn to_naive_datetime(&self) -> NaiveDateTime {
+    let local = self.date.and_time(self.time.unwrap_or(NaiveTime::MIN));
+    local - TimeDelta::minutes(self.offset_minutes.unwrap_or(0) as i64)
+  }
+
+  /// Render as with fuzzy_to_datetime_string with default options,
+  /// e.g. "2023-08-29T19:34:39.678Z" or "2023-08-29T19:34:39.678+05:30"
+  pub fn to_iso_string(&self) -> String {
+    let time = self.time.unwrap_or(NaiveTime::MIN);
+    let zone = self.offset_minutes.map_or_else(|| "Z".to_string(), format_utc_offset);
+    format!("{}T{}.{}{}", self.date.format("%Y-%m-%d"), time.format("%H:%M:%S"), format_subseconds(time.nanosecond(), 3), zone)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fuzzy_to_datetime_string;
+
+  #[test]
+  fn test_parsed_datetime() {
+    let parsed = ParsedDateTime::parse("29/08/2023 19:34:39.678901+05:30", None).unwrap();
+    assert_eq!(parsed.date, NaiveDate::from_ymd_opt(2023, 8, 29).unwrap());
+    assert_eq!(parsed.time, NaiveTime::from_hms_micro_opt(19, 34, 39, 678_901));
+    assert_eq!(parsed.offset_minutes, Some(330));
+    assert_eq!(parsed.granularity, Granularity::Subsecond);
+    assert_eq!(parsed.order, Some(DateOrder::DMY));
+    assert!(!parsed.ambiguous);
+    assert_eq!(parsed.to_naive_datetime(), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_micro_opt(14, 4, 39, 678_901).unwrap());
+    let ambiguous = ParsedDateTime::parse("05/06/2023", None).unwrap();
+    assert_eq!((ambiguous.time, ambiguous.granularity, ambiguous.ambiguous), (None, Granularity::Day, true));
+    assert!(!ParsedDateTime::parse("05/06/2023", Some(DateOptions::mdy('/'))).unwrap().ambiguous);
+    let named = ParsedDateTime::parse("Aug 2023", None).unwrap();
+    assert_eq!((named.order, named.granularity), (None, Granularity::Month));
+    assert_eq!(ParsedDateTime::parse("1990s", None).map(|parsed| parsed.granularity), Some(Granularity::Decade));
+    assert_eq!(ParsedDateTime::parse("2023-13-01", None), None);
+  }
+
+  #[test]
+  fn test_parsed_datetime_matches_string_output() {
+    let samples = [
+      "2023-08-29", "29.08.2023 19:34", "08/29/2023 7:15 pm", "2023-08-29T19:34:39.678Z",
+      "2023-08-29 19:34:39+02", "20230829 193439", "Tuesday, August 29, 2023", "2023-08-29 19:34.5",
+    ];
+    for sample in samples {
+      let parsed = ParsedDateTime::parse(sample, None).unwrap();
+      assert_eq!(Some(parsed.to_iso_string()), fuzzy_to_datetime_string(sample, None, None), "rendering {:?}", sample);
+      // the rendered string reads back to the same components
+      let reparsed = ParsedDateTime::parse(&parsed.to_iso_string(), None).unwrap();
+      assert_eq!(reparsed.to_iso_string(), parsed.to_iso_string());
+    }
+  }
+}
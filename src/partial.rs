@@ -0,0 +1,125 @@
+use std::ops::RangeInclusive;
+use simple_string_patterns::CharGroupMatch;
+use crate::{converters::days_in_month, split_datetime_segments, DateOptions, DateOrder};
+
+/// Check whether a date or date-time typed so far could still become valid with more input,
+/// e.g. "2023-1" may become 2023-10 to 2023-12, but "2023-13" can never be a valid date.
+/// Each component is range-checked as far as it has been entered, and a time may only follow a complete date.
+/// If date_opts is None, the order and splitter are guessed, but fixed options are more reliable for short prefixes
+pub fn is_valid_partial(s: &str, date_opts: Option<DateOptions>) -> bool {
+  let segments = split_datetime_segments(s);
+  let opts = date_opts.unwrap_or_else(DateOptions::auto).resolve(segments.date);
+  let date_parts: Vec<&str> = match opts.splitter() {
+    Some(splitter) => segments.date.split(splitter).collect(),
+    None => match split_compact_date_prefix(segments.date, opts.order()) {
+      Some(date_parts) => date_parts,
+      None => return false,
+    },
+  };
+  match date_prefix_completeness(&date_parts, opts.order()) {
+    Some(true) => segments.time.is_none_or(|time| {
+      let time_parts: Vec<&str> = time.split(':').collect();
+      time_parts.len() <= 3 && time_parts.iter().enumerate().all(|(idx, part)| {
+        let max = if idx == 0 { 23 } else { 59 };
+        is_valid_component_prefix(part, 0..=max, idx == time_parts.len() - 1)
+      })
+    }),
+    Some(false) => segments.time.is_none(),
+    None => false,
+  }
+}
+
+/// Slice the digits of a compact date typed so far into components of up to 4 digits for the year and 2 otherwise.
+/// None if the date has anything other than ASCII digits
+fn split_compact_date_prefix(date: &str, order: DateOrder) -> Option<Vec<&str>> {
+  if !date.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  let (yr_idx, _, _) = order.to_ymd_indices();
+  let mut parts = vec![];
+  let mut start = 0;
+  for idx in 0..3 {
+    if start >= date.len() && idx > 0 {
+      break;
+    }
+    let width = if idx == yr_idx { 4 } else { 2 };
+    let end = (start + width).min(date.len());
+    parts.push(&date[start..end]);
+    start = end;
+  }
+  // excess digits form a 4th component, which is always invalid
+  if start < date.len() {
+    parts.push(&date[start..]);
+  }
+  Some(parts)
+}
+
+/// None if the date components can never form a valid date, otherwise whether the date is complete
+fn date_prefix_completeness(parts: &[&str], order: DateOrder) -> Option<bool> {
+  if parts.len() > 3 {
+    return None;
+  }
+  let (yr_idx, month_idx, day_idx) = order.to_ymd_indices();
+  let last_idx = parts.len() - 1;
+  for (idx, &part) in parts.iter().enumerate() {
+    let is_last = idx == last_idx;
+    let is_valid = if idx == yr_idx {
+      // a year before other components must be complete, but a final one may have 2 digits
+      let min_len = if is_last { 0 } else { 4 };
+      (part.is_empty() || part.is_digits_only()) && (min_len..=4).contains(&part.len()) && !(part.len() > 2 && part.starts_with('0'))
+    } else {
+      let max = if idx == month_idx { 12 } else { 31 };
+      is_valid_component_prefix(part, 1..=max, is_last)
+    };
+    if !is_valid {
+      return None;
+    }
+  }
+  let number = |idx: usize| parts.get(idx).filter(|part| part.len() >= 2).and_then(|part| part.parse::<u16>().ok());
+  // with a known month, the day must exist in it, in a leap year until the year is known
+  if let (Some(month), Some(day)) = (number(month_idx), number(day_idx)) {
+    let year = number(yr_idx).filter(|&yr| yr >= 1000).unwrap_or(2000);
+    if day > days_in_month(year, month as u8) as u16 {
+      return None;
+    }
+  }
+  let yr_len = parts.get(yr_idx).map_or(0, |part| part.len());
+  Some(parts.len() == 3 && parts.iter().all(|part| !part.is_empty()) && (yr_len == 4 || (yr_len == 2 && yr_idx == last_idx)))
+}
+
+/// A number of up to 2 digits within the range, or any single digit if more may follow
+fn is_valid_component_prefix(digits: &str, range: RangeInclusive<u16>, is_last: bool) -> bool {
+  if digits.is_empty() {
+    return is_last;
+  }
+  if !digits.is_digits_only() || digits.len() > 2 {
+    return false;
+  }
+  (is_last && digits.len() == 1) || digits.parse::<u16>().is_ok_and(|value| range.contains(&value))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_is_valid_partial() {
+    let opts = DateOptions::default();
+    for partial in ["", "2", "2023", "2023-", "2023-0", "2023-1", "2023-12", "2023-08-3", "2023-08-29", "2023-08-29 19:3"] {
+      assert!(is_valid_partial(partial, Some(opts.clone())), "{:?} is a valid partial", partial);
+    }
+    for partial in ["2023-13", "2023-00", "2023-08-32", "2023-02-30", "2023--", "2023-08-29-", "2023-08 19", "2023-08-29 24", "2023-08-29 19:60"] {
+      assert!(!is_valid_partial(partial, Some(opts.clone())), "{:?} is not a valid partial", partial);
+    }
+    // day first with slashes, or compact digits
+    assert!(is_valid_partial("29/0", Some(DateOptions::dmy('/'))));
+    assert!(is_valid_partial("29/02/2", Some(DateOptions::dmy('/'))));
+    assert!(!is_valid_partial("31/04", Some(DateOptions::dmy('/'))));
+    assert!(is_valid_partial("202308", Some(DateOptions::new(DateOrder::YMD, None))));
+    assert!(!is_valid_partial("202313", Some(DateOptions::new(DateOrder::YMD, None))));
+    assert!(!is_valid_partial("202308291", Some(DateOptions::new(DateOrder::YMD, None))));
+    assert!(!is_valid_partial("2023a", Some(DateOptions::new(DateOrder::YMD, None))));
+    // guessed as a compact date, but not made of digits
+    assert!(!is_valid_partial("dayZˢᵗ", None));
+  }
+}
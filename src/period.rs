@@ -0,0 +1,64 @@
+use simple_string_patterns::CharGroupMatch;
+
+/// Whether a date with missing components, e.g. 2023-08 or 2023-Q3, resolves to the start
+/// or the end of the period it describes. End suits financial "as of" dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PeriodAnchor {
+  #[default]
+  Start,
+  End,
+}
+
+/// A calendar period for grouping dates, e.g. in time series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+  Year,
+  Month,
+  Quarter,
+  /// An ISO 8601 week from Monday to Sunday, which may belong to the previous or next year near New Year
+  Week,
+}
+
+/// Parse a quarter or half year such as "2023-Q3", "Q3 2023" or "2023-H2"
+/// returning the year with the first and last months of the period
+pub(crate) fn quarter_or_half_to_months(text: &str) -> Option<(u16, u8, u8)> {
+  let compact: String = text.trim().to_uppercase().chars().filter(|c| !matches!(c, ' ' | '-' | '/' | '.')).collect();
+  let (year_str, period) = if compact.starts_with(['Q', 'H']) {
+    (compact.get(2..)?, compact.get(..2)?)
+  } else {
+    let split_idx = compact.len().checked_sub(2)?;
+    (compact.get(..split_idx)?, compact.get(split_idx..)?)
+  };
+  if year_str.len() != 4 || !year_str.is_digits_only() {
+    return None;
+  }
+  let year = year_str.parse::<u16>().ok()?;
+  let (first_month, num_months) = match period {
+    "Q1" => (1, 3),
+    "Q2" => (4, 3),
+    "Q3" => (7, 3),
+    "Q4" => (10, 3),
+    "H1" => (1, 6),
+    "H2" => (7, 6),
+    _ => return None,
+  };
+  Some((year, first_month, first_month + num_months - 1))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_quarter_or_half_to_months() {
+    assert_eq!(quarter_or_half_to_months("2023-Q3"), Some((2023, 7, 9)));
+    assert_eq!(quarter_or_half_to_months("q1 2024"), Some((2024, 1, 3)));
+    assert_eq!(quarter_or_half_to_months("2023 H2"), Some((2023, 7, 12)));
+    assert_eq!(quarter_or_half_to_months("2023-Q5"), None);
+    assert_eq!(quarter_or_half_to_months("2023-08"), None);
+    // too short or with a multibyte character after the period letter
+    for text in ["H", "Q", "Hé"] {
+      assert_eq!(quarter_or_half_to_months(text), None, "parsing {:?}", text);
+    }
+  }
+}
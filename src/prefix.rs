@@ -0,0 +1,64 @@
+use chrono::NaiveDateTime;
+use crate::{explain::first_unexpected_word, fuzzy_to_date_string_with_optional_time, fuzzy_to_datetime, DateOptions};
+
+/// The most whitespace-separated tokens a date-time may span, e.g. "Tuesday 29 August 2023 7:34 pm"
+const MAX_TOKENS: usize = 6;
+
+/// Parse a date or date-time at the start of a string and return it with the number of bytes consumed,
+/// including any leading whitespace, e.g. 20 for "2023-08-29T19:34:39Z rest", so a tokenizer can resume there.
+/// The longest run of leading tokens with digits or date words that parses is taken, but trailing tokens that
+/// don't change the parsed date, time or zone are left unconsumed, as is a comma or semicolon after the date-time
+pub fn parse_datetime_len(s: &str, date_opts: Option<DateOptions>) -> Option<(NaiveDateTime, usize)> {
+  let mut token_ends = vec![];
+  let mut token_start = None;
+  for (idx, c) in s.char_indices().chain([(s.len(), ' ')]) {
+    match (c.is_whitespace(), token_start) {
+      (true, Some(start)) => {
+        token_ends.push((idx, is_date_token(&s[start..idx])));
+        token_start = None;
+      },
+      (false, None) => token_start = Some(idx),
+      _ => (),
+    }
+  }
+  // stop at the first token that can't belong to a date-time, as trailing text may be ignored when parsing
+  let candidates: Vec<&str> = token_ends.into_iter().take(MAX_TOKENS)
+    .take_while(|&(_, is_date_token)| is_date_token)
+    .map(|(end, _)| s[..end].trim_end_matches([',', ';']))
+    .collect();
+  let parse = |candidate: &str| fuzzy_to_datetime(candidate, date_opts.clone(), None).ok();
+  let mut idx = candidates.iter().rposition(|candidate| parse(candidate).is_some())?;
+  let components = |candidate: &str| fuzzy_to_date_string_with_optional_time(candidate, date_opts.clone());
+  while idx > 0 && parse(candidates[idx - 1]).is_some() && components(candidates[idx - 1]) == components(candidates[idx]) {
+    idx -= 1;
+  }
+  parse(candidates[idx]).map(|datetime| (datetime, candidates[idx].len()))
+}
+
+/// A token with digits, e.g. "29th" or "+05:30", or only words found in dates, e.g. "August" or "p.m."
+fn is_date_token(token: &str) -> bool {
+  token.chars().any(char::is_alphanumeric) && first_unexpected_word(token).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+
+  #[test]
+  fn test_parse_datetime_len() {
+    let datetime = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(19, 34, 39).unwrap();
+    let text = "2023-08-29T19:34:39Z rest";
+    assert_eq!(parse_datetime_len(text, None), Some((datetime, 20)));
+    assert_eq!(&text[20..], " rest");
+    assert_eq!(parse_datetime_len("  29/08/2023 19:34:39, then more", None), Some((datetime, 21)));
+    assert_eq!(parse_datetime_len("29 August 2023 7:34:39 pm – Zoë", None), Some((datetime, 25)));
+    assert_eq!(parse_datetime_len("2023-08-29 19:34:39 +00:00 42 apples", None), Some((datetime, 26)));
+    let midnight = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(parse_datetime_len("2023-08-29 00:00 café", None), Some((midnight, 16)));
+    assert_eq!(parse_datetime_len("2023-08-29 café", None), Some((midnight, 10)));
+    assert_eq!(parse_datetime_len("rest 2023-08-29", None), None);
+    assert!(is_date_token("p.m."));
+    assert!(!is_date_token("–"));
+  }
+}
@@ -0,0 +1,88 @@
+use chrono::{Datelike, NaiveDate};
+use crate::{fuzzy_to_date, DateOptions, DateOrder, PeriodAnchor};
+
+/// words or dashes joining the start and end dates of a range, tried in this order
+const RANGE_CONNECTORS: [&str; 7] = [" until ", " till ", " through ", " thru ", " to ", " – ", " - "];
+
+/// Parse a date range such as "2023-08-01 to 2023-08-29" or "from 1 January until 31 December 2023"
+/// with an optional leading "from" and "until", "till", "through", "thru", "to" or a spaced dash between the dates.
+/// A start date without a year takes the year of the end date, or the year before if it would otherwise
+/// follow the end date, e.g. "from 1 December to 6 January 2024". Partial dates cover their whole period,
+/// so "2023-01 to 2023-03" ends on 31 March. Two years joined by a dash, e.g. "2020-2023", span from 1 January
+/// of the first to 31 December of the second. Returns None if the end date precedes the start date
+pub fn fuzzy_to_date_range(text: &str, date_opts: Option<DateOptions>) -> Option<(NaiveDate, NaiveDate)> {
+  let date_opts = date_opts.unwrap_or_else(DateOptions::auto);
+  let trimmed = text.trim();
+  if let Some((start_year, end_year)) = split_year_range(trimmed) {
+    return Some((NaiveDate::from_ymd_opt(start_year, 1, 1)?, NaiveDate::from_ymd_opt(end_year, 12, 31)?));
+  }
+  // ASCII lowercasing keeps byte offsets aligned with the original text
+  let lc = trimmed.to_ascii_lowercase();
+  let offset = if lc.starts_with("from ") { 5 } else { 0 };
+  let (connector_idx, connector) = RANGE_CONNECTORS.iter().find_map(|&connector| lc[offset..].find(connector).map(|idx| (offset + idx, connector)))?;
+  let start_str = trimmed[offset..connector_idx].trim();
+  let end_str = trimmed[connector_idx + connector.len()..].trim();
+  let end = fuzzy_to_date(end_str, Some(date_opts.clone().with_period_anchor(PeriodAnchor::End))).ok()?;
+  let start_opts = date_opts.clone().with_period_anchor(PeriodAnchor::Start);
+  let start = match fuzzy_to_date(start_str, Some(start_opts.clone())) {
+    Ok(start) => start,
+    Err(_) => {
+      let end_format = date_opts.resolve(end_str);
+      let with_year = |year: i32| {
+        let dated_str = match (end_format.splitter(), end_format.order()) {
+          (Some(sep), DateOrder::YMD) if !start_str.contains(' ') => format!("{}{}{}", year, sep, start_str),
+          (Some(sep), _) if !start_str.contains(' ') => format!("{}{}{}", start_str, sep, year),
+          _ => format!("{} {}", start_str, year),
+        };
+        fuzzy_to_date(&dated_str, Some(start_opts.clone().with_format(end_format.order(), end_format.splitter()))).ok()
+      };
+      let start = with_year(end.year())?;
+      if start > end {
+        with_year(end.year() - 1)?
+      } else {
+        start
+      }
+    }
+  };
+  if start <= end {
+    Some((start, end))
+  } else {
+    None
+  }
+}
+
+/// Two 4-digit years joined by a hyphen or en dash without spaces, e.g. "2020-2023".
+/// The end year may not precede the start year, so "2020-1012" is not mistaken for a range
+fn split_year_range(text: &str) -> Option<(i32, i32)> {
+  let (start, end) = text.split_once(['-', '–'])?;
+  let to_year = |part: &str| Some(part).filter(|part| part.len() == 4 && part.bytes().all(|b| b.is_ascii_digit()) && !part.starts_with('0')).and_then(|part| part.parse::<i32>().ok());
+  let (start_year, end_year) = (to_year(start)?, to_year(end)?);
+  Some((start_year, end_year)).filter(|_| end_year >= start_year)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fuzzy_to_date_range() {
+    assert_eq!(fuzzy_to_date_range("2023-08-01 to 2023-08-29", None), Some((NaiveDate::from_ymd_opt(2023, 8, 1).unwrap(), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap())));
+    assert_eq!(fuzzy_to_date_range("from 1 January until 31 December 2023", None), Some((NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2023, 12, 31).unwrap())));
+    assert_eq!(fuzzy_to_date_range("From 1 Dec through 6 Jan 2024", None), Some((NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(), NaiveDate::from_ymd_opt(2024, 1, 6).unwrap())));
+    assert_eq!(fuzzy_to_date_range("29/07 - 29/08/2023", None), Some((NaiveDate::from_ymd_opt(2023, 7, 29).unwrap(), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap())));
+    assert_eq!(fuzzy_to_date_range("2023-01 to 2023-03", None), Some((NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2023, 3, 31).unwrap())));
+    // the end precedes the start
+    assert_eq!(fuzzy_to_date_range("2023-08-29 to 2023-08-01", None), None);
+    assert_eq!(fuzzy_to_date_range("2023-08-29", None), None);
+  }
+
+  #[test]
+  fn test_year_range() {
+    assert_eq!(fuzzy_to_date_range("2020-2023", None), Some((NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2023, 12, 31).unwrap())));
+    assert_eq!(fuzzy_to_date_range("1999–2001", None), Some((NaiveDate::from_ymd_opt(1999, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2001, 12, 31).unwrap())));
+    assert_eq!(crate::fuzzy_to_date_string("2020-2023", None), None);
+    // the second number can't be a later year
+    assert_eq!(fuzzy_to_date_range("2020-1012", None), None);
+    assert_eq!(fuzzy_to_date_range("2023-2020", None), None);
+  }
+}
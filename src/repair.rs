@@ -0,0 +1,66 @@
+use crate::{fuzzy_to_datetime, fuzzy_to_datetime_string, split_datetime_segments};
+
+/// Fix obvious typos in a date-time string and return it as an ISO 8601 string,
+/// e.g. a 5-digit year with one doubled digit such as "20023" => "2023" or a truncated "7:15P" => "7:15PM".
+/// A month or day of 00 already resolves to 01. Each repair is only applied when unambiguous,
+/// so None is returned if the string cannot be repaired
+pub fn repair_datetime(s: &str) -> Option<String> {
+  let parse = |dt: &str| fuzzy_to_datetime(dt, None, None).ok().and_then(|_| fuzzy_to_datetime_string(dt, None, None));
+  // the repairs leave valid input unchanged, while a truncated AM/PM marker could otherwise be ignored
+  let repaired = repair_meridiem(&repair_year_typos(s.trim()));
+  parse(&repaired)
+}
+
+/// Collapse the only doubled digit of a 5-digit number in the date, e.g. "20023" or "22023" => "2023"
+fn repair_year_typos(dt: &str) -> String {
+  let date = split_datetime_segments(dt).date;
+  let mut repaired = String::with_capacity(dt.len());
+  let mut rest = date;
+  while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+    repaired.push_str(&rest[..start]);
+    let digits_end = rest[start..].find(|c: char| !c.is_ascii_digit()).map_or(rest.len(), |len| start + len);
+    let digits = &rest[start..digits_end];
+    let doubled: Vec<usize> = (1..digits.len()).filter(|&idx| digits.as_bytes()[idx] == digits.as_bytes()[idx - 1]).collect();
+    match doubled.as_slice() {
+      [idx] if digits.len() == 5 => {
+        repaired.push_str(&digits[..*idx]);
+        repaired.push_str(&digits[idx + 1..]);
+      },
+      _ => repaired.push_str(digits),
+    }
+    rest = &rest[digits_end..];
+  }
+  repaired.push_str(rest);
+  repaired.push_str(&dt[date.len()..]);
+  repaired
+}
+
+/// Complete a lone A or P marker after a time, e.g. "7:15P" => "7:15PM"
+fn repair_meridiem(dt: &str) -> String {
+  let base = dt.trim_end_matches(['a', 'A', 'p', 'P']);
+  let is_truncated = dt.len() - base.len() == 1 && base.trim_end().ends_with(|c: char| c.is_ascii_digit());
+  if is_truncated {
+    format!("{}M", dt)
+  } else {
+    dt.to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_repair_datetime() {
+    assert_eq!(repair_datetime("20023-08-29"), Some("2023-08-29T00:00:00.000Z".to_string()));
+    assert_eq!(repair_datetime("29/08/22023 19:34"), Some("2023-08-29T19:34:00.000Z".to_string()));
+    assert_eq!(repair_datetime("2023-08-29 7:15P"), Some("2023-08-29T19:15:00.000Z".to_string()));
+    assert_eq!(repair_datetime("2023-08-29 7 a"), Some("2023-08-29T07:00:00.000Z".to_string()));
+    assert_eq!(repair_datetime("2023-00-00 19:34"), Some("2023-01-01T19:34:00.000Z".to_string()));
+    // valid input is returned unchanged
+    assert_eq!(repair_datetime("2023-08-29T19:34:39Z"), Some("2023-08-29T19:34:39.000Z".to_string()));
+    // ambiguous typos are not repaired
+    assert_eq!(repair_datetime("20235-08-29"), None);
+    assert_eq!(repair_datetime("20003-08-29"), None);
+  }
+}
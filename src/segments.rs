@@ -0,0 +1,101 @@
+use crate::{offset::{split_zone_suffix, utc_offset_minutes}, NumberLocale, validators::{is_plausible_time, segment_is_subseconds, segment_is_subseconds_after_time}};
+
+/// The date, time, fraction of a second and zone of a date-time string as borrowed slices,
+/// e.g. "2023-08-29", "19:34:39", "678" and "Z" in "2023-08-29T19:34:39.678Z"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DateTimeSegments<'a> {
+  pub date: &'a str,
+  pub time: Option<&'a str>,
+  pub fraction: Option<&'a str>,
+  pub zone: Option<&'a str>,
+}
+
+/// Split a date-time string into its date, time, subsecond and zone segments.
+/// The date ends at the first whitespace or a 'T' or 't' time designator following the date.
+/// The fraction and zone are only sought in the time, so dots in dates such as 29.08.2023 are never
+/// mistaken for subseconds, and a fraction only counts as subseconds after a time with seconds,
+/// e.g. 19:34:39.7, or if it has 3 or more digits, e.g. 19.34.39.678
+/// The fraction may follow a decimal point or a decimal comma, e.g. 19:34:39,678
+/// A UTC designator or numeric offset may be attached to the time or follow it after whitespace
+pub fn split_datetime_segments(dt: &str) -> DateTimeSegments<'_> {
+  split_datetime_segments_by(dt, NumberLocale::Any)
+}
+
+/// As above, but only the fraction separators of the number locale are recognised
+pub(crate) fn split_datetime_segments_by(dt: &str, number_locale: NumberLocale) -> DateTimeSegments<'_> {
+  let trimmed = dt.trim();
+  // the time designator may be lowercase, e.g. 2023-08-29t19:34, but must follow a digit
+  let mut prev_is_digit = false;
+  let date_end = trimmed.char_indices()
+    .find(|&(_, c)| {
+      let is_end = c.is_whitespace() || (matches!(c, 'T' | 't') && prev_is_digit);
+      prev_is_digit = c.is_ascii_digit();
+      is_end
+    })
+    .map(|(idx, _)| idx)
+    .unwrap_or(trimmed.len());
+  let (date, rest) = trimmed.split_at(date_end);
+  let mut tokens = rest.strip_prefix(['T', 't']).unwrap_or(rest).split_whitespace();
+  let mut segments = DateTimeSegments { date, ..Default::default() };
+  if let Some(token) = tokens.next() {
+    let (time, fraction, zone) = split_time_segments(token, number_locale);
+    segments.time = Some(time);
+    segments.fraction = fraction;
+    // the zone may also follow the time after a space, e.g. 19:34:39 +05:30
+    segments.zone = zone.or_else(|| tokens.next().filter(|&token| utc_offset_minutes(token).is_some()));
+  }
+  segments
+}
+
+/// Split a time without a date into the time itself, any fraction of a second and any zone,
+/// e.g. "19:34:39", "678" and "+05:30" in "19:34:39.678+05:30"
+pub(crate) fn split_time_segments(token: &str, number_locale: NumberLocale) -> (&str, Option<&str>, Option<&str>) {
+  let (time, zone) = split_zone_suffix(token);
+  let (time, fraction) = match time.rsplit_once(number_locale.fraction_separators()) {
+    Some((base, fraction)) if (segment_is_subseconds(fraction) && is_plausible_time(base)) || segment_is_subseconds_after_time(base, fraction) => (base, Some(fraction)),
+    _ => (time, None),
+  };
+  (time, fraction, Some(zone).filter(|zone| !zone.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_datetime_segments() {
+    assert_eq!(split_datetime_segments("2023-08-29T19:34:39.678Z"), DateTimeSegments {
+      date: "2023-08-29", time: Some("19:34:39"), fraction: Some("678"), zone: Some("Z")
+    });
+    assert_eq!(split_datetime_segments(" 2023-08-29 19:34:39.678901+00 "), DateTimeSegments {
+      date: "2023-08-29", time: Some("19:34:39"), fraction: Some("678901"), zone: Some("+00")
+    });
+    assert_eq!(split_datetime_segments("29.08.2023 19:34:39.7"), DateTimeSegments {
+      date: "29.08.2023", time: Some("19:34:39"), fraction: Some("7"), zone: None
+    });
+    assert_eq!(split_datetime_segments("2023-08-29T19:34:39-08:00"), DateTimeSegments {
+      date: "2023-08-29", time: Some("19:34:39"), fraction: None, zone: Some("-08:00")
+    });
+    assert_eq!(split_datetime_segments("2023-08-29 19:34:39.678 +05:30"), DateTimeSegments {
+      date: "2023-08-29", time: Some("19:34:39"), fraction: Some("678"), zone: Some("+05:30")
+    });
+    assert_eq!(split_datetime_segments("2023-08-29 19:34:39 Z").zone, Some("Z"));
+    assert_eq!(split_datetime_segments("2023-08-29 19:34:39 - 20:00").zone, None);
+    assert_eq!(split_datetime_segments("2023-08-29t19:34").time, Some("19:34"));
+    assert_eq!(split_datetime_segments("Oct 5").date, "Oct");
+    // dots in a date or a time without seconds are not subseconds
+    assert_eq!(split_datetime_segments("29.08.2023"), DateTimeSegments { date: "29.08.2023", ..Default::default() });
+    assert_eq!(split_datetime_segments("20230829 19.34").time, Some("19.34"));
+    assert_eq!(split_datetime_segments("20230829 19.34.39").fraction, None);
+    assert_eq!(split_datetime_segments("20230829 19.34.39.678").fraction, Some("678"));
+    // nor are the trailing digits of a version number
+    assert_eq!(split_datetime_segments("2023-08-29 1.2.345").fraction, None);
+    assert_eq!(split_time_segments("1.2.345", NumberLocale::Any), ("1.2.345", None, None));
+    assert_eq!(split_datetime_segments("29.08.2023 19:34:39,678").fraction, Some("678"));
+    assert_eq!(split_datetime_segments_by("29.08.2023 19:34:39,678", NumberLocale::DecimalPoint).fraction, None);
+    assert_eq!(split_datetime_segments_by("29.08.2023 19:34:39.678", NumberLocale::DecimalComma).fraction, None);
+    // text with multibyte characters after a comma is not a fraction
+    assert_eq!(split_datetime_segments("29.08.2023 19:34,13²"), DateTimeSegments { date: "29.08.2023", time: Some("19:34,13²"), ..Default::default() });
+    assert_eq!(split_datetime_segments("29.08.2023 19:34:39,6é7").fraction, None);
+  }
+}
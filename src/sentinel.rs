@@ -0,0 +1,20 @@
+use chrono::{Datelike, NaiveDate};
+
+/// Check whether a date is 9999-12-31, used by many business systems as a sentinel for "never"
+/// or an open-ended validity period rather than a real date.
+/// It parses as a literal date unless DateOptions::with_reject_sentinels is set
+pub fn is_sentinel_date(date: &NaiveDate) -> bool {
+  date.year() == 9999 && date.month() == 12 && date.day() == 31
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_is_sentinel_date() {
+    assert!(is_sentinel_date(&NaiveDate::from_ymd_opt(9999, 12, 31).unwrap()));
+    assert!(!is_sentinel_date(&NaiveDate::from_ymd_opt(9999, 12, 30).unwrap()));
+    assert!(!is_sentinel_date(&NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()));
+  }
+}
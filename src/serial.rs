@@ -0,0 +1,121 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta};
+
+/// Epoch strings with at least this many digits, excluding any sign, count milliseconds rather than seconds
+const EPOCH_MILLIS_MIN_DIGITS: usize = 12;
+
+/// Epoch seconds within this margin of the 32-bit limit are flagged as likely overflow
+const Y2038_MARGIN_SECS: i64 = 86_400;
+
+/// Convert a spreadsheet date serial, as stored by Excel, to a date-time. The integer part counts days
+/// from 1899-12-30 and the fraction is the time of day, e.g. 45167.5 => 2023-08-29T12:00:00.
+/// Excel treats 1900 as a leap year, so serials before 1 March 1900 count from 1899-12-31
+/// and serial 60, the nonexistent 29 February 1900, is rejected. Times are rounded to milliseconds
+pub fn excel_serial_to_datetime(value: f64) -> Option<NaiveDateTime> {
+  if !value.is_finite() || value < 1.0 {
+    return None;
+  }
+  let days = value.trunc() as i64;
+  let epoch = match days {
+    60 => return None,
+    0..60 => NaiveDate::from_ymd_opt(1899, 12, 31)?,
+    _ => NaiveDate::from_ymd_opt(1899, 12, 30)?,
+  };
+  let millis = (value.fract() * 86_400_000.0).round() as i64;
+  epoch.and_hms_opt(0, 0, 0)?
+    .checked_add_signed(TimeDelta::try_days(days)?)?
+    .checked_add_signed(TimeDelta::try_milliseconds(millis)?)
+}
+
+/// Why Unix epoch seconds may come from a corrupted or overflowing 32-bit timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochWarning {
+  /// Before 1970, often a wrapped-around 32-bit counter
+  Negative,
+  /// Within a day of the 32-bit limit at 2038-01-19T03:14:07Z or beyond it
+  Year2038,
+}
+
+/// Convert Unix epoch seconds to a UTC date-time, e.g. 1693337679 => 2023-08-29T19:34:39
+pub fn epoch_to_datetime(seconds: i64) -> Option<NaiveDateTime> {
+  DateTime::from_timestamp(seconds, 0).map(|datetime| datetime.naive_utc())
+}
+
+/// Convert signed Unix epoch milliseconds to a UTC date-time, e.g. -86400000 => 1969-12-31T00:00:00
+pub fn epoch_millis_to_datetime(millis: i64) -> Option<NaiveDateTime> {
+  DateTime::from_timestamp_millis(millis).map(|datetime| datetime.naive_utc())
+}
+
+/// Parse a Unix timestamp string with an optional sign, e.g. "1693337679" or "-86400" for 1969-12-31.
+/// Timestamps of 12 or more digits are read as milliseconds, e.g. "1693337679678"
+pub fn parse_epoch(s: &str) -> Option<NaiveDateTime> {
+  let trimmed = s.trim();
+  let digits = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+  if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+    return None;
+  }
+  let value = trimmed.parse::<i64>().ok()?;
+  if digits.len() >= EPOCH_MILLIS_MIN_DIGITS {
+    epoch_millis_to_datetime(value)
+  } else {
+    epoch_to_datetime(value)
+  }
+}
+
+/// As epoch_to_datetime, but also flag negative values or values at or near the Year 2038 boundary,
+/// which often signal integer overflow in a source system storing 32-bit timestamps
+pub fn epoch_to_datetime_flagged(seconds: i64) -> Option<(NaiveDateTime, Option<EpochWarning>)> {
+  let warning = if seconds < 0 {
+    Some(EpochWarning::Negative)
+  } else if seconds >= i32::MAX as i64 - Y2038_MARGIN_SECS {
+    Some(EpochWarning::Year2038)
+  } else {
+    None
+  };
+  epoch_to_datetime(seconds).map(|datetime| (datetime, warning))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_excel_serial_to_datetime() {
+    assert_eq!(excel_serial_to_datetime(45167.5), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(12, 0, 0));
+    assert_eq!(excel_serial_to_datetime(45167.0), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(excel_serial_to_datetime(45167.75), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(18, 0, 0));
+    // either side of the fictitious 29 February 1900
+    assert_eq!(excel_serial_to_datetime(1.0), NaiveDate::from_ymd_opt(1900, 1, 1).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(excel_serial_to_datetime(59.0), NaiveDate::from_ymd_opt(1900, 2, 28).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(excel_serial_to_datetime(60.0), None);
+    assert_eq!(excel_serial_to_datetime(61.0), NaiveDate::from_ymd_opt(1900, 3, 1).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(excel_serial_to_datetime(-1.0), None);
+    assert_eq!(excel_serial_to_datetime(f64::NAN), None);
+  }
+
+  #[test]
+  fn test_epoch_to_datetime() {
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(19, 34, 39).unwrap();
+    assert_eq!(epoch_to_datetime(1_693_337_679), Some(expected));
+    assert_eq!(epoch_to_datetime_flagged(1_693_337_679), Some((expected, None)));
+    // near and past 2^31
+    let (datetime, warning) = epoch_to_datetime_flagged(2_147_483_000).unwrap();
+    assert_eq!(datetime, NaiveDate::from_ymd_opt(2038, 1, 19).unwrap().and_hms_opt(3, 3, 20).unwrap());
+    assert_eq!(warning, Some(EpochWarning::Year2038));
+    assert_eq!(epoch_to_datetime_flagged(2_147_483_648).map(|(_, warning)| warning), Some(Some(EpochWarning::Year2038)));
+    assert_eq!(epoch_to_datetime_flagged(-2_147_483_648).map(|(_, warning)| warning), Some(Some(EpochWarning::Negative)));
+    assert_eq!(epoch_to_datetime(i64::MAX), None);
+  }
+
+  #[test]
+  fn test_signed_epochs() {
+    assert_eq!(parse_epoch("-86400"), NaiveDate::from_ymd_opt(1969, 12, 31).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(parse_epoch("+86400"), NaiveDate::from_ymd_opt(1970, 1, 2).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(epoch_millis_to_datetime(-86_400_000), NaiveDate::from_ymd_opt(1969, 12, 31).unwrap().and_hms_opt(0, 0, 0));
+    assert_eq!(parse_epoch("-100000000000"), NaiveDate::from_ymd_opt(1966, 10, 31).unwrap().and_hms_opt(14, 13, 20));
+    assert_eq!(parse_epoch(" 1693337679678 "), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_milli_opt(19, 34, 39, 678));
+    assert_eq!(parse_epoch("1693337679"), NaiveDate::from_ymd_opt(2023, 8, 29).unwrap().and_hms_opt(19, 34, 39));
+    for invalid in ["", "-", "--86400", "86400.5", "1e9"] {
+      assert_eq!(parse_epoch(invalid), None, "parsing {:?}", invalid);
+    }
+  }
+}
@@ -0,0 +1,38 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{format_date, format_datetime, DateOrder};
+
+/// This trait may be implemented by any Date or DateTime object
+/// Implementations for chrono::NaiveDate and chrono::NaiveDateTime are provided below
+pub trait ToFuzzyString {
+
+  /// Render in the given date order with the given splitter, e.g. 29/08/2023 in DMY order with '/'
+  fn to_fuzzy_string(&self, order: DateOrder, splitter: char) -> String;
+
+}
+
+/// Implement the ToFuzzyString trait for NaiveDate
+impl ToFuzzyString for NaiveDate {
+  fn to_fuzzy_string(&self, order: DateOrder, splitter: char) -> String {
+    format_date(*self, order, splitter)
+  }
+}
+
+/// Implement the ToFuzzyString trait for NaiveDateTime with the time after the date, e.g. 29/08/2023 19:34:39
+impl ToFuzzyString for NaiveDateTime {
+  fn to_fuzzy_string(&self, order: DateOrder, splitter: char) -> String {
+    format_datetime(*self, order, splitter)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_fuzzy_string() {
+    let date = NaiveDate::from_ymd_opt(2023, 8, 29).unwrap();
+    assert_eq!(date.to_fuzzy_string(DateOrder::DMY, '/'), "29/08/2023");
+    assert_eq!(date.and_hms_opt(7, 5, 0).unwrap().to_fuzzy_string(DateOrder::MDY, '-'), "08-29-2023 07:05:00");
+  }
+}
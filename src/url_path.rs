@@ -0,0 +1,45 @@
+use chrono::NaiveDate;
+use simple_string_patterns::CharGroupMatch;
+use crate::{converters::to_formatted_date_string_anchored, DateOptions, DateOrder};
+
+/// Extract a date embedded in a URL or slug path as /YYYY/MM/DD/ or /YYYY/MM/, e.g. "/2023/08/29/post-title"
+/// Only slash-delimited segments are considered. The period anchor in the options decides the day of a
+/// year-and-month path and dates outside any valid range are skipped
+pub fn extract_date_from_path(path: &str, date_opts: Option<DateOptions>) -> Option<NaiveDate> {
+  let date_opts = date_opts.unwrap_or_default();
+  // ignore any query string or fragment
+  let path = path.split(['?', '#']).next().unwrap_or_default();
+  let segments: Vec<&str> = path.split('/').collect();
+  let is_unit = |segment: &str| (1..=2).contains(&segment.len()) && segment.is_digits_only();
+  segments.windows(2).enumerate().find_map(|(idx, pair)| {
+    let (year, month) = (pair[0], pair[1]);
+    if year.len() != 4 || !year.is_digits_only() || !is_unit(month) {
+      return None;
+    }
+    let date_str = match segments.get(idx + 2).filter(|day| is_unit(day)) {
+      Some(day) => format!("{}-{}-{}", year, month, day),
+      None => format!("{}-{}", year, month),
+    };
+    let formatted_date = to_formatted_date_string_anchored(&date_str, DateOrder::YMD, Some('-'), date_opts.period_anchor(), date_opts.two_digit_years())?;
+    NaiveDate::parse_from_str(&formatted_date, "%Y-%m-%d").ok().filter(|date| date_opts.is_date_in_valid_range(date))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::PeriodAnchor;
+
+  #[test]
+  fn test_extract_date_from_path() {
+    let expected = NaiveDate::from_ymd_opt(2023, 8, 29);
+    assert_eq!(extract_date_from_path("/2023/08/29/post-title", None), expected);
+    assert_eq!(extract_date_from_path("https://example.com/blog/2023/08/29/post-title?ref=home", None), expected);
+    assert_eq!(extract_date_from_path("/2023/08/post-title", None), NaiveDate::from_ymd_opt(2023, 8, 1));
+    let opts = DateOptions::default().with_period_anchor(PeriodAnchor::End);
+    assert_eq!(extract_date_from_path("/archive/2023/08/", Some(opts)), NaiveDate::from_ymd_opt(2023, 8, 31));
+    // no year and month segments
+    assert_eq!(extract_date_from_path("/posts/2023-08-29-post-title", None), None);
+    assert_eq!(extract_date_from_path("/2023/13/01/post-title", None), None);
+  }
+}
@@ -1,12 +1,14 @@
 use simple_string_patterns::CharGroupMatch;
+use crate::offset::split_zone_suffix;
 
 /// check if athe captured last segment represents milliseconds, microseconds or nanoseconds with an optional character at at the end
 pub(crate) fn segment_is_subseconds(segment: &str) -> bool {
-    let s_len = segment.len();
+    // count characters rather than bytes, so a trailing multibyte character is never split
+    let s_len = segment.chars().count();
     if s_len >= 3 {
       if s_len > 3 {
-        let last = &segment[s_len - 1..];
-        let head = &segment[..s_len - 1];
+        let last_len = segment.chars().last().map_or(0, char::len_utf8);
+        let (head, last) = segment.split_at(segment.len() - last_len);
         // The trailing character must be a genuine non-digit timezone-ish indicator (e.g.
         // "678Z") for this to be milliseconds-plus-suffix -- `last.has_alphanumeric()`
         // used to accept *any* alphanumeric character here, and a digit is alphanumeric
@@ -20,4 +22,160 @@ pub(crate) fn segment_is_subseconds(segment: &str) -> bool {
     } else {
       false
     }
-  }
\ No newline at end of file
+  }
+
+/// check if a segment after a full hours:minutes:seconds time, e.g. the "7" in 19:34:39.7 or
+/// the "678901+00" in 19:34:39.678901+00, is a fraction of a second with an optional UTC offset.
+/// The time may also be a compact ISO 8601 time, e.g. 193439.7
+/// Digits beyond nanoseconds, e.g. from high-precision instruments, still count and are truncated when parsed.
+/// This only applies after a full time, as short segments after a dot are otherwise more likely
+/// to be minutes or seconds, e.g. in 19.34.39, and longer ones years, e.g. in 29.08.2023
+pub(crate) fn segment_is_subseconds_after_time(base: &str, segment: &str) -> bool {
+  let (digits, _) = split_zone_suffix(segment);
+  let time_base = base.trim().rsplit(|c: char| c.is_whitespace() || c == 'T').next().unwrap_or("");
+  let is_full_time = time_base.matches(':').count() == 2 || (time_base.len() == 6 && time_base.is_digits_only());
+  !digits.is_empty() && digits.is_digits_only() && is_full_time
+}
+
+/// check if a time before a possible fraction of a second is plausible on its own, with an hour of 1 or 2 digits
+/// and 2-digit minutes and seconds within range, separated by colons or dots, e.g. 19:34:39, 7.05 or 1934,
+/// so the trailing digits of a version number such as 1.2.345 are never read as subseconds
+pub(crate) fn is_plausible_time(time: &str) -> bool {
+  let parts: Vec<&str> = if time.contains([':', '.']) {
+    time.split([':', '.']).collect()
+  } else if matches!(time.len(), 2 | 4 | 6) {
+    (0..time.len()).step_by(2).filter_map(|idx| time.get(idx..idx + 2)).collect()
+  } else {
+    return false;
+  };
+  parts.len() <= 3 && parts.iter().enumerate().all(|(idx, part)| {
+    let (widths, max) = if idx == 0 { (1..=2, 23) } else { (2..=2, 59) };
+    widths.contains(&part.len()) && part.is_digits_only() && part.parse::<u8>().is_ok_and(|value| value <= max)
+  })
+}
+
+/// check if any component of a separated date is padded with more leading zeros than its width allows,
+/// e.g. the month in 2023-008-29. Zero-padded days and months have 2 digits and years need none
+pub(crate) fn has_excess_leading_zeros(date_str: &str, splitter: char) -> bool {
+  date_str.split(splitter).any(|part| part.trim().len() > 2 && part.trim().starts_with('0'))
+}
+
+/// list the distinct date splitters ('-', '/' or '.') in a date in order of appearance,
+/// e.g. ['-', '/'] for the malformed 2023-08/29
+pub(crate) fn observed_splitters(date_str: &str) -> Vec<char> {
+  let mut splitters = vec![];
+  for c in date_str.chars().filter(|c| matches!(c, '-' | '/' | '.')) {
+    if !splitters.contains(&c) {
+      splitters.push(c);
+    }
+  }
+  splitters
+}
+
+/// check if a date has exactly 3 numeric components separated by the splitter,
+/// or 8 digits for a compact date without a splitter
+pub(crate) fn is_complete_date_with_splitter(date_str: &str, splitter: Option<char>) -> bool {
+  match splitter {
+    Some(split_char) => {
+      let parts: Vec<&str> = date_str.split(split_char).collect();
+      parts.len() == 3 && parts.iter().all(|part| !part.is_empty() && part.is_digits_only())
+    },
+    None => date_str.len() == 8 && date_str.is_digits_only(),
+  }
+}
+
+/// check if a string is an ISO 8601 duration such as P1W, P3Y6M4DT12H30M5S or PT36H rather than a date
+pub(crate) fn is_iso_duration(text: &str) -> bool {
+  if let Some(rest) = text.trim().strip_prefix(['P', 'p']) {
+    rest.has_digits() && rest.chars().all(|c| c.is_ascii_digit() || matches!(c.to_ascii_uppercase(), 'Y' | 'M' | 'W' | 'D' | 'T' | 'H' | 'S' | '.' | ','))
+  } else {
+    false
+  }
+}
+
+/// check if a string is a time without a date, e.g. 19:34 or 19:34:39.678
+pub(crate) fn is_time_only(text: &str) -> bool {
+  let trimmed = text.trim();
+  if let Some((hours, _)) = trimmed.split_once(':') {
+    (1..=2).contains(&hours.len()) && hours.is_digits_only() && trimmed.chars().all(|c| c.is_ascii_digit() || matches!(c, ':' | '.' | ','))
+  } else {
+    false
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_segment_is_subseconds() {
+    assert!(segment_is_subseconds("678"));
+    assert!(segment_is_subseconds("678Z"));
+    assert!(!segment_is_subseconds("2026"));
+    // multibyte characters are never split
+    assert!(!segment_is_subseconds("13²"));
+    assert!(!segment_is_subseconds("1²3"));
+    assert!(segment_is_subseconds("678é"));
+  }
+
+  #[test]
+  fn test_is_plausible_time() {
+    for time in ["19:34:39", "19.34", "7:05", "19", "1934", "193439"] {
+      assert!(is_plausible_time(time), "{:?} is a plausible time", time);
+    }
+    for time in ["1.2", "123", "24:00", "19:60", "19:3", "19:34:39:01", "19-34"] {
+      assert!(!is_plausible_time(time), "{:?} is not a plausible time", time);
+    }
+  }
+
+  #[test]
+  fn test_segment_is_subseconds_after_time() {
+    assert!(segment_is_subseconds_after_time("2023-08-29 19:34:39", "7"));
+    assert!(segment_is_subseconds_after_time("2023-08-29T19:34:39", "07Z"));
+    assert!(segment_is_subseconds_after_time("2023-08-29 19:34:39", "678901"));
+    assert!(segment_is_subseconds_after_time("2023-08-29 19:34:39", "678901+00"));
+    assert!(!segment_is_subseconds_after_time("2023-08-29 19.34", "39"));
+    assert!(!segment_is_subseconds_after_time("2023-08-29 19:34", "5"));
+    assert!(segment_is_subseconds_after_time("20230829T193439", "7Z"));
+    assert!(!segment_is_subseconds_after_time("20230829T1934", "5"));
+    assert!(segment_is_subseconds_after_time("2023-08-29 19:34:39", "678901234567"));
+  }
+
+  #[test]
+  fn test_has_excess_leading_zeros() {
+    assert!(has_excess_leading_zeros("2023-008-29", '-'));
+    assert!(has_excess_leading_zeros("0029/08/2023", '/'));
+    assert!(!has_excess_leading_zeros("2023-08-09", '-'));
+    assert!(!has_excess_leading_zeros("9.8.2023", '.'));
+  }
+
+  #[test]
+  fn test_observed_splitters() {
+    assert_eq!(observed_splitters("2023-08/29"), vec!['-', '/']);
+    assert_eq!(observed_splitters("29.08.2023"), vec!['.']);
+    assert_eq!(observed_splitters("20230829"), vec![]);
+  }
+
+  #[test]
+  fn test_is_complete_date_with_splitter() {
+    assert!(is_complete_date_with_splitter("2023-08-29", Some('-')));
+    assert!(is_complete_date_with_splitter("20230829", None));
+    assert!(!is_complete_date_with_splitter("29.08.2023", Some('/')));
+    assert!(!is_complete_date_with_splitter("2023-08", Some('-')));
+    assert!(!is_complete_date_with_splitter("2023-08-29", None));
+  }
+
+  #[test]
+  fn test_non_date_entries() {
+    assert!(is_iso_duration("P1W"));
+    assert!(is_iso_duration("PT36H"));
+    assert!(is_iso_duration("P3Y6M4DT12H30M5S"));
+    assert!(!is_iso_duration("Paris"));
+    assert!(!is_iso_duration("2023-08-29"));
+
+    assert!(is_time_only("19:34"));
+    assert!(is_time_only("23:59:59.678"));
+    assert!(!is_time_only("2023-08-29 19:34"));
+    assert!(!is_time_only("2023:08:29"));
+  }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use chrono::NaiveDate;
+use simple_string_patterns::CharGroupMatch;
+use crate::converters::{alpha_month_to_index, days_in_month};
+
+/// A year and month without a day, preserving the granularity of inputs such as "2023-08"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth(pub u16, pub u8);
+
+impl YearMonth {
+  pub fn year(&self) -> u16 {
+    self.0
+  }
+
+  pub fn month(&self) -> u8 {
+    self.1
+  }
+
+  /// the first day of the month
+  pub fn first_day(&self) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(self.0 as i32, self.1 as u32, 1)
+  }
+
+  /// the last day of the month
+  pub fn last_day(&self) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(self.0 as i32, self.1 as u32, days_in_month(self.0, self.1) as u32)
+  }
+}
+
+/// render as an ISO 8601 year and month, e.g. 2023-08
+impl fmt::Display for YearMonth {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-{:02}", self.0, self.1)
+  }
+}
+
+/// Parse a year and month in either order with a 4-digit year, e.g. "2023-08", "08/2023" or "Aug 2023"
+/// Strings with a day, such as "2023-08-29", are too precise and return None
+pub fn fuzzy_to_year_month(dt: &str) -> Option<YearMonth> {
+  let parts: Vec<&str> = dt.trim().split(['-', '/', '.', ' ', ',']).filter(|p| !p.is_empty()).collect();
+  let (year_str, month_str) = match parts.as_slice() {
+    [first, second] if first.len() == 4 => (*first, *second),
+    [first, second] if second.len() == 4 => (*second, *first),
+    _ => return None,
+  };
+  if !year_str.is_digits_only() {
+    return None;
+  }
+  let year = year_str.parse::<u16>().ok()?;
+  let month = if month_str.is_digits_only() {
+    month_str.parse::<u8>().ok()?
+  } else {
+    alpha_month_to_index(month_str)?
+  };
+  if (1..=12).contains(&month) {
+    Some(YearMonth(year, month))
+  } else {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fuzzy_to_year_month() {
+    assert_eq!(fuzzy_to_year_month("2023-08"), Some(YearMonth(2023, 8)));
+    assert_eq!(fuzzy_to_year_month("08/2023"), Some(YearMonth(2023, 8)));
+    assert_eq!(fuzzy_to_year_month("Aug 2023"), Some(YearMonth(2023, 8)));
+    assert_eq!(fuzzy_to_year_month("2023-8").map(|ym| ym.to_string()), Some("2023-08".to_string()));
+    // too precise
+    assert_eq!(fuzzy_to_year_month("2023-08-29"), None);
+    assert_eq!(fuzzy_to_year_month("2023-13"), None);
+    assert_eq!(fuzzy_to_year_month("2023"), None);
+  }
+
+  #[test]
+  fn test_year_month_bounds() {
+    let ym = YearMonth(2024, 2);
+    assert_eq!(ym.first_day(), NaiveDate::from_ymd_opt(2024, 2, 1));
+    assert_eq!(ym.last_day(), NaiveDate::from_ymd_opt(2024, 2, 29));
+  }
+}